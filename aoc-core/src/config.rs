@@ -0,0 +1,256 @@
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+use crate::{InputSource, Params};
+
+/// One of the two halves of a puzzle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+
+    pub fn number(self) -> u32 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.number())
+    }
+}
+
+impl FromStr for Part {
+    type Err = ConfigError;
+
+    fn from_str(s: &str) -> Result<Part, ConfigError> {
+        match s {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            _ => Err(ConfigError::InvalidPart(s.to_string())),
+        }
+    }
+}
+
+/// How answers are written to stdout.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OutputFormat {
+    #[default]
+    Text,
+    /// One JSON object per answer and line.
+    Json,
+}
+
+impl FromStr for OutputFormat {
+    type Err = ConfigError;
+
+    fn from_str(s: &str) -> Result<OutputFormat, ConfigError> {
+        match s {
+            "text" => Ok(OutputFormat::Text),
+            "json" => Ok(OutputFormat::Json),
+            _ => Err(ConfigError::InvalidFormat(s.to_string())),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum ConfigError {
+    MissingValue(&'static str),
+    InvalidValue(&'static str, String),
+    InvalidPart(String),
+    InvalidFormat(String),
+    UnknownFlag(String),
+    UnexpectedArgument(String),
+    ExampleWithInput,
+    StepWithStdin,
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConfigError::MissingValue(flag) => write!(f, "missing value for {flag}"),
+            ConfigError::InvalidValue(flag, value) => {
+                write!(f, "invalid value `{value}` for {flag}")
+            }
+            ConfigError::InvalidPart(part) => {
+                write!(f, "invalid part `{part}`, expected 1 or 2")
+            }
+            ConfigError::InvalidFormat(format) => {
+                write!(f, "invalid output format `{format}`, expected text or json")
+            }
+            ConfigError::UnknownFlag(flag) => write!(f, "unknown flag {flag}"),
+            ConfigError::UnexpectedArgument(arg) => write!(f, "unexpected argument `{arg}`"),
+            ConfigError::ExampleWithInput => {
+                write!(f, "--example cannot be combined with an input")
+            }
+            ConfigError::StepWithStdin => {
+                write!(
+                    f,
+                    "--step reads commands from stdin, which cannot also be the input"
+                )
+            }
+        }
+    }
+}
+
+impl Error for ConfigError {}
+
+/// Options for a single solver invocation.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Config {
+    /// Explicit input; the runner falls back to the day's puzzle input.
+    pub input: Option<InputSource>,
+    /// Run against the solution's embedded example instead of an input.
+    pub example: bool,
+    pub part: Option<Part>,
+    pub verbosity: u8,
+    pub format: OutputFormat,
+    /// Solver-specific settings from `--set key=value`.
+    pub params: Params,
+    /// Pause at solver checkpoints and wait for commands on stdin.
+    pub step: bool,
+}
+
+impl Config {
+    /// Builds a config from the arguments that follow the subcommand:
+    /// `[part] [input] [-p <part>] [--example] [-v...] [--format <format>]
+    /// [--set <key=value>...] [--step]`.
+    ///
+    /// A lone positional that is `1` or `2` selects the part, anything else
+    /// is taken as the input path (`-` reads from stdin).
+    pub fn build(args: &[String]) -> Result<Config, ConfigError> {
+        let mut config = Config::default();
+        let mut positionals = Vec::new();
+        let mut args = args.iter();
+
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "-p" | "--part" => {
+                    let value = args.next().ok_or(ConfigError::MissingValue("--part"))?;
+                    config.part = Some(value.parse()?);
+                }
+                "--format" => {
+                    let value = args.next().ok_or(ConfigError::MissingValue("--format"))?;
+                    config.format = value.parse()?;
+                }
+                "--set" => {
+                    let value = args.next().ok_or(ConfigError::MissingValue("--set"))?;
+                    let params: Params = value
+                        .parse()
+                        .map_err(|_| ConfigError::InvalidValue("--set", value.to_string()))?;
+                    config.params.extend(params);
+                }
+                "--example" => config.example = true,
+                "--step" => config.step = true,
+                "--verbose" => config.verbosity += 1,
+                flag if flag.starts_with("-v") && flag[1..].chars().all(|c| c == 'v') => {
+                    config.verbosity += (flag.len() - 1) as u8;
+                }
+                flag if flag.starts_with('-') && flag != "-" => {
+                    return Err(ConfigError::UnknownFlag(flag.to_string()));
+                }
+                positional => positionals.push(positional),
+            }
+        }
+
+        match positionals[..] {
+            [] => {}
+            [arg] => match arg.parse::<Part>() {
+                Ok(part) => config.part = Some(part),
+                Err(_) => config.input = Some(InputSource::from(arg)),
+            },
+            [part, input] => {
+                config.part = Some(part.parse()?);
+                config.input = Some(InputSource::from(input));
+            }
+            [_, _, extra, ..] => {
+                return Err(ConfigError::UnexpectedArgument(extra.to_string()));
+            }
+        }
+
+        if config.example && config.input.is_some() {
+            return Err(ConfigError::ExampleWithInput);
+        }
+
+        if config.step && config.input == Some(InputSource::Stdin) {
+            return Err(ConfigError::StepWithStdin);
+        }
+
+        Ok(config)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn build(args: &[&str]) -> Result<Config, ConfigError> {
+        let args: Vec<String> = args.iter().map(|arg| arg.to_string()).collect();
+        Config::build(&args)
+    }
+
+    #[test]
+    fn lone_positional_is_part_or_input() {
+        assert_eq!(build(&["2"]).unwrap().part, Some(Part::Two));
+        assert_eq!(
+            build(&["input.txt"]).unwrap().input,
+            Some(InputSource::from("input.txt"))
+        );
+        assert_eq!(build(&["-"]).unwrap().input, Some(InputSource::Stdin));
+    }
+
+    #[test]
+    fn flags() {
+        let config = build(&["-p", "1", "-vv", "--verbose", "--format", "json"]).unwrap();
+        assert_eq!(config.part, Some(Part::One));
+        assert_eq!(config.verbosity, 3);
+        assert_eq!(config.format, OutputFormat::Json);
+    }
+
+    #[test]
+    fn params() {
+        let config = build(&["--set", "a=1", "--set", "b=x=y", "--set", "a=2"]).unwrap();
+        assert_eq!(config.params.get::<u32>("a").unwrap(), Some(2));
+        assert_eq!(
+            config.params.get::<String>("b").unwrap().as_deref(),
+            Some("x=y")
+        );
+        assert!(matches!(
+            build(&["--set", "a"]),
+            Err(ConfigError::InvalidValue("--set", _))
+        ));
+    }
+
+    #[test]
+    fn rejects_bad_arguments() {
+        assert!(matches!(
+            build(&["3", "x"]),
+            Err(ConfigError::InvalidPart(_))
+        ));
+        assert!(matches!(
+            build(&["--part"]),
+            Err(ConfigError::MissingValue(_))
+        ));
+        assert!(matches!(build(&["-x"]), Err(ConfigError::UnknownFlag(_))));
+        assert!(matches!(
+            build(&["1", "a", "b"]),
+            Err(ConfigError::UnexpectedArgument(_))
+        ));
+        assert!(matches!(
+            build(&["--example", "-"]),
+            Err(ConfigError::ExampleWithInput)
+        ));
+        assert!(matches!(
+            build(&["--step", "-"]),
+            Err(ConfigError::StepWithStdin)
+        ));
+    }
+}
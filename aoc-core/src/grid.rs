@@ -0,0 +1,330 @@
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+use crate::{lines, ParseError};
+
+/// A `(row, column)` position in a [`Grid`].
+pub type Pos = (usize, usize);
+
+/// Offsets of the four orthogonal neighbours: up, right, down, left.
+pub const NEIGHBORS_4: [(isize, isize); 4] = [(-1, 0), (0, 1), (1, 0), (0, -1)];
+
+/// Offsets of all eight neighbours, clockwise from up-left.
+pub const NEIGHBORS_8: [(isize, isize); 8] = [
+    (-1, -1),
+    (-1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+    (1, 0),
+    (1, -1),
+    (0, -1),
+];
+
+/// A rectangular grid stored row by row.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    rows: usize,
+    cols: usize,
+}
+
+impl<T> Grid<T> {
+    /// A `rows` x `cols` grid with every cell set to `value`.
+    ///
+    /// Panics if `cols` is 0, which [`Grid::parse`] rejects too.
+    pub fn new(rows: usize, cols: usize, value: T) -> Grid<T>
+    where
+        T: Clone,
+    {
+        assert!(cols > 0, "a grid needs at least one column");
+        Grid {
+            cells: vec![value; rows * cols],
+            rows,
+            cols,
+        }
+    }
+
+    /// A `rows` x `cols` grid with each cell computed from its position.
+    ///
+    /// Panics if `cols` is 0, as [`Grid::new`] does.
+    pub fn from_fn(rows: usize, cols: usize, mut f: impl FnMut(Pos) -> T) -> Grid<T> {
+        assert!(cols > 0, "a grid needs at least one column");
+        let cells = (0..rows * cols).map(|i| f((i / cols, i % cols))).collect();
+        Grid { cells, rows, cols }
+    }
+
+    /// Parses one cell per character, one row per line. `cell` returns `None`
+    /// for characters that are not valid tiles.
+    pub fn parse(
+        input: &str,
+        mut cell: impl FnMut(char) -> Option<T>,
+    ) -> Result<Grid<T>, ParseError> {
+        let mut cells = Vec::new();
+        let mut rows = 0;
+        let mut cols = None;
+
+        for line in lines(input) {
+            let start = cells.len();
+            for (offset, c) in line.text.char_indices() {
+                let tile = &line.text[offset..offset + c.len_utf8()];
+                cells.push(cell(c).ok_or_else(|| line.error(tile, "invalid tile"))?);
+            }
+
+            let width = cells.len() - start;
+            if *cols.get_or_insert(width) != width {
+                return Err(line.error(line.text, "rows differ in length"));
+            }
+            rows += 1;
+        }
+
+        match cols {
+            Some(cols) if cols > 0 => Ok(Grid { cells, rows, cols }),
+            _ => Err(ParseError::new(1, 1, "", "empty grid")),
+        }
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    pub fn contains(&self, (row, col): Pos) -> bool {
+        row < self.rows && col < self.cols
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        self.contains(pos)
+            .then(|| &self.cells[pos.0 * self.cols + pos.1])
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        if self.contains(pos) {
+            Some(&mut self.cells[pos.0 * self.cols + pos.1])
+        } else {
+            None
+        }
+    }
+
+    /// `pos` moved by `(rows, cols)`, if that is still inside the grid.
+    pub fn offset(&self, (row, col): Pos, (rows, cols): (isize, isize)) -> Option<Pos> {
+        let pos = (row.checked_add_signed(rows)?, col.checked_add_signed(cols)?);
+        self.contains(pos).then_some(pos)
+    }
+
+    /// The in-bounds orthogonal neighbours of `pos`, in [`NEIGHBORS_4`] order.
+    pub fn neighbors4(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        NEIGHBORS_4
+            .into_iter()
+            .filter_map(move |delta| self.offset(pos, delta))
+    }
+
+    /// The in-bounds neighbours of `pos` including diagonals, in
+    /// [`NEIGHBORS_8`] order.
+    pub fn neighbors8(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        NEIGHBORS_8
+            .into_iter()
+            .filter_map(move |delta| self.offset(pos, delta))
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.cols..(row + 1) * self.cols]
+    }
+
+    pub fn iter_rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.cols)
+    }
+
+    /// The cells of column `col`, top to bottom. Panics if it is outside
+    /// the grid.
+    pub fn column(&self, col: usize) -> impl Iterator<Item = &T> {
+        assert!(
+            col < self.cols,
+            "column {col} is outside of a {}x{} grid",
+            self.rows,
+            self.cols
+        );
+        self.cells[col..].iter().step_by(self.cols)
+    }
+
+    pub fn iter_columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.cols).map(|col| self.column(col))
+    }
+
+    /// Every cell with its position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        let cols = self.cols;
+        self.cells
+            .iter()
+            .enumerate()
+            .map(move |(i, cell)| ((i / cols, i % cols), cell))
+    }
+
+    /// Positions of every cell matching `predicate`, row by row.
+    pub fn positions<'a>(
+        &'a self,
+        mut predicate: impl FnMut(&T) -> bool + 'a,
+    ) -> impl Iterator<Item = Pos> + 'a {
+        self.iter()
+            .filter(move |(_, cell)| predicate(cell))
+            .map(|(pos, _)| pos)
+    }
+
+    /// The first position, row by row, whose cell matches `predicate`.
+    pub fn position(&self, predicate: impl FnMut(&T) -> bool) -> Option<Pos> {
+        self.positions(predicate).next()
+    }
+
+    /// The first position, row by row, holding `value`.
+    pub fn find(&self, value: &T) -> Option<Pos>
+    where
+        T: PartialEq,
+    {
+        self.position(|cell| cell == value)
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            cells: self.cells.iter().map(f).collect(),
+            rows: self.rows,
+            cols: self.cols,
+        }
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &T {
+        match self.get(pos) {
+            Some(cell) => cell,
+            None => panic!("{pos:?} is outside of a {}x{} grid", self.rows, self.cols),
+        }
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut T {
+        let (rows, cols) = (self.rows, self.cols);
+        match self.get_mut(pos) {
+            Some(cell) => cell,
+            None => panic!("{pos:?} is outside of a {rows}x{cols} grid"),
+        }
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, row) in self.iter_rows().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid() -> Grid<char> {
+        Grid::parse("abc\ndef", Some).unwrap()
+    }
+
+    #[test]
+    fn parses_rows_and_columns() {
+        let grid = grid();
+        assert_eq!((grid.rows(), grid.cols()), (2, 3));
+        assert_eq!(grid[(1, 2)], 'f');
+        assert_eq!(grid.row(1), ['d', 'e', 'f']);
+        assert_eq!(grid.column(1).collect::<String>(), "be");
+        assert_eq!(grid.iter_columns().count(), 3);
+        assert_eq!(grid.to_string(), "abc\ndef");
+    }
+
+    #[test]
+    fn parse_errors() {
+        let err = Grid::parse("ab\nabc", Some).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 1: rows differ in length: `abc`"
+        );
+
+        let err = Grid::parse("..\n.x", |c| (c == '.').then_some(c)).unwrap_err();
+        assert_eq!(err.to_string(), "line 2, column 2: invalid tile: `x`");
+
+        assert!(Grid::parse("", Some).is_err());
+    }
+
+    #[test]
+    fn bounds_checked_access() {
+        let grid = grid();
+        assert_eq!(grid.get((0, 0)), Some(&'a'));
+        assert_eq!(grid.get((2, 0)), None);
+        assert_eq!(grid.get((0, 3)), None);
+        assert_eq!(grid.offset((0, 0), (-1, 0)), None);
+        assert_eq!(grid.offset((0, 0), (1, 2)), Some((1, 2)));
+    }
+
+    #[test]
+    #[should_panic(expected = "(2, 0) is outside of a 2x3 grid")]
+    fn indexing_out_of_bounds_panics() {
+        let _ = grid()[(2, 0)];
+    }
+
+    #[test]
+    #[should_panic(expected = "column 3 is outside of a 2x3 grid")]
+    fn column_out_of_bounds_panics() {
+        let _ = grid().column(3);
+    }
+
+    #[test]
+    #[should_panic(expected = "a grid needs at least one column")]
+    fn new_rejects_zero_columns() {
+        let _ = Grid::new(2, 0, '.');
+    }
+
+    #[test]
+    #[should_panic(expected = "a grid needs at least one column")]
+    fn from_fn_rejects_zero_columns() {
+        let _ = Grid::from_fn(2, 0, |_| '.');
+    }
+
+    #[test]
+    fn neighbors_stay_inside() {
+        let grid = grid();
+        assert_eq!(
+            grid.neighbors4((0, 0)).collect::<Vec<_>>(),
+            [(0, 1), (1, 0)]
+        );
+        assert_eq!(
+            grid.neighbors4((1, 1)).collect::<Vec<_>>(),
+            [(0, 1), (1, 2), (1, 0)]
+        );
+        assert_eq!(grid.neighbors8((0, 1)).count(), 5);
+        assert_eq!(grid.neighbors8((1, 1)).count(), 5);
+    }
+
+    #[test]
+    fn queries() {
+        let grid = grid();
+        assert_eq!(grid.find(&'e'), Some((1, 1)));
+        assert_eq!(grid.find(&'z'), None);
+        assert_eq!(
+            grid.positions(|c| "aeiou".contains(*c)).collect::<Vec<_>>(),
+            [(0, 0), (1, 1)]
+        );
+        assert_eq!(grid.map(|c| c.is_ascii_uppercase()), Grid::new(2, 3, false));
+        assert_eq!(
+            Grid::from_fn(2, 2, |(r, c)| r * 2 + c).to_string(),
+            "01\n23"
+        );
+    }
+}
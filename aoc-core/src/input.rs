@@ -0,0 +1,143 @@
+use std::error::Error;
+use std::fmt;
+use std::fs;
+use std::io::{self, Read};
+use std::path::PathBuf;
+
+/// Directory searched for puzzle inputs when no input is given.
+pub const INPUTS_DIR: &str = "inputs";
+
+/// Where a puzzle input is read from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    Stdin,
+    File(PathBuf),
+    /// `<dir>/<year>/<day>.txt`, with the day zero-padded (`inputs/2023/07.txt`).
+    Puzzle {
+        dir: PathBuf,
+        year: u32,
+        day: u32,
+    },
+    /// Example text compiled into the solution crate.
+    Example(&'static str),
+}
+
+impl InputSource {
+    /// The conventional location of a day's input under [`INPUTS_DIR`].
+    pub fn puzzle(year: u32, day: u32) -> InputSource {
+        InputSource::Puzzle {
+            dir: PathBuf::from(INPUTS_DIR),
+            year,
+            day,
+        }
+    }
+
+    /// The file this source reads from, if any.
+    pub fn path(&self) -> Option<PathBuf> {
+        match self {
+            InputSource::File(path) => Some(path.clone()),
+            InputSource::Puzzle { dir, year, day } => {
+                Some(dir.join(year.to_string()).join(format!("{day:02}.txt")))
+            }
+            InputSource::Stdin | InputSource::Example(_) => None,
+        }
+    }
+
+    /// Reads the whole input.
+    pub fn read(&self) -> Result<String, InputError> {
+        let result = match self {
+            InputSource::Stdin => {
+                let mut input = String::new();
+                io::stdin().read_to_string(&mut input).map(|_| input)
+            }
+            InputSource::Example(text) => Ok(text.to_string()),
+            _ => fs::read_to_string(self.path().expect("file sources have a path")),
+        };
+
+        result.map_err(|error| InputError {
+            source: self.clone(),
+            error,
+        })
+    }
+}
+
+impl From<&str> for InputSource {
+    fn from(arg: &str) -> InputSource {
+        match arg {
+            "-" => InputSource::Stdin,
+            path => InputSource::File(PathBuf::from(path)),
+        }
+    }
+}
+
+impl fmt::Display for InputSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputSource::Stdin => write!(f, "stdin"),
+            InputSource::Example(_) => write!(f, "example"),
+            _ => write!(f, "{}", self.path().unwrap_or_default().display()),
+        }
+    }
+}
+
+/// A short stable fingerprint of an input (64-bit FNV-1a, in hex), so results
+/// can be matched to the input they were computed from.
+pub fn input_hash(input: &str) -> String {
+    let hash = input.bytes().fold(0xcbf2_9ce4_8422_2325_u64, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+    });
+    format!("{hash:016x}")
+}
+
+/// An input source that could not be read.
+#[derive(Debug)]
+pub struct InputError {
+    pub source: InputSource,
+    pub error: io::Error,
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "could not read {}: {}", self.source, self.error)
+    }
+}
+
+impl Error for InputError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn puzzle_inputs_follow_the_convention() {
+        let source = InputSource::puzzle(2023, 7);
+        assert_eq!(source.path(), Some(PathBuf::from("inputs/2023/07.txt")));
+        assert_eq!(source.to_string(), "inputs/2023/07.txt");
+    }
+
+    #[test]
+    fn reads_embedded_examples() {
+        let source = InputSource::Example("1abc2\n");
+        assert_eq!(source.read().unwrap(), "1abc2\n");
+        assert_eq!(source.path(), None);
+    }
+
+    #[test]
+    fn missing_file_names_the_source() {
+        let err = InputSource::from("does/not/exist.txt").read().unwrap_err();
+        assert!(err
+            .to_string()
+            .starts_with("could not read does/not/exist.txt: "));
+    }
+
+    #[test]
+    fn input_hash_is_stable() {
+        assert_eq!(input_hash(""), "cbf29ce484222325");
+        assert_eq!(input_hash("a"), "af63dc4c8601ec8c");
+        assert_ne!(input_hash("ab"), input_hash("ba"));
+    }
+}
@@ -0,0 +1,20 @@
+//! Building blocks shared by every day's solution and the `aoc` runner.
+
+pub mod config;
+pub mod grid;
+pub mod input;
+pub mod math;
+pub mod params;
+pub mod parse;
+pub mod range_set;
+pub mod search;
+pub mod solution;
+pub mod step;
+
+pub use config::{Config, ConfigError, OutputFormat, Part};
+pub use grid::{Grid, Pos};
+pub use input::{input_hash, InputError, InputSource};
+pub use params::{ParamError, Params};
+pub use parse::{lines, Line, ParseError};
+pub use range_set::RangeSet;
+pub use solution::{Answer, Registry, Runnable, Solution, Timing, Unsolved};
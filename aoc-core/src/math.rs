@@ -0,0 +1,209 @@
+//! Integer number theory that several days need: gcd and lcm, modular
+//! inverses, the Chinese remainder theorem and integer square roots.
+
+use std::error::Error;
+use std::fmt;
+
+pub fn gcd(a: u64, b: u64) -> u64 {
+    let (mut a, mut b) = (a, b);
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+/// The least common multiple, or `None` if it does not fit in a `u64`.
+pub fn lcm(a: u64, b: u64) -> Option<u64> {
+    if a == 0 || b == 0 {
+        return Some(0);
+    }
+    (a / gcd(a, b)).checked_mul(b)
+}
+
+/// The gcd of every value; 0 for none.
+pub fn gcd_all(values: impl IntoIterator<Item = u64>) -> u64 {
+    values.into_iter().fold(0, gcd)
+}
+
+/// The lcm of every value; 1 for none, `None` on overflow.
+pub fn lcm_all(values: impl IntoIterator<Item = u64>) -> Option<u64> {
+    values.into_iter().try_fold(1, lcm)
+}
+
+/// `(g, x, y)` with `g = gcd(a, b) = a * x + b * y` and `g >= 0`.
+pub fn extended_gcd(a: i64, b: i64) -> (i64, i64, i64) {
+    // Only gcd(i64::MIN, 0) and gcd(i64::MIN, i64::MIN) leave the range.
+    let narrow = |n: i128| i64::try_from(n).expect("extended gcd out of range");
+    let (g, x, y) = bezout(a as i128, b as i128);
+    (narrow(g), narrow(x), narrow(y))
+}
+
+/// [`extended_gcd`] on wider integers, so `u64` moduli fit.
+fn bezout(a: i128, b: i128) -> (i128, i128, i128) {
+    let (mut r0, mut r1) = (a, b);
+    let (mut x0, mut x1) = (1, 0);
+    let (mut y0, mut y1) = (0, 1);
+    while r1 != 0 {
+        let q = r0 / r1;
+        (r0, r1) = (r1, r0 - q * r1);
+        (x0, x1) = (x1, x0 - q * x1);
+        (y0, y1) = (y1, y0 - q * y1);
+    }
+    if r0 < 0 {
+        (-r0, -x0, -y0)
+    } else {
+        (r0, x0, y0)
+    }
+}
+
+/// The `x` in `0..m` with `a * x` congruent to 1 modulo `m`, if `a` and `m`
+/// are coprime.
+pub fn mod_inverse(a: i64, m: i64) -> Option<i64> {
+    assert!(m > 0, "modulus must be positive");
+    let (g, x, _) = extended_gcd(a.rem_euclid(m), m);
+    (g == 1).then(|| x.rem_euclid(m))
+}
+
+/// Why [`crt`] found no single congruence.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CrtError {
+    /// No number satisfies every congruence.
+    Inconsistent,
+    /// The combined modulus does not fit in a `u64`.
+    Overflow,
+}
+
+impl fmt::Display for CrtError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CrtError::Inconsistent => write!(f, "the congruences have no common solution"),
+            CrtError::Overflow => write!(f, "the combined modulus overflows"),
+        }
+    }
+}
+
+impl Error for CrtError {}
+
+/// Combines congruences `x ≡ r (mod m)` into one, `(r, m)` with `r < m`
+/// and `m` the lcm of the moduli. Moduli need not be coprime.
+pub fn crt(congruences: impl IntoIterator<Item = (u64, u64)>) -> Result<(u64, u64), CrtError> {
+    congruences
+        .into_iter()
+        .try_fold((0, 1), |(r1, m1), (r2, m2)| {
+            assert!(m2 > 0, "modulus must be positive");
+            let r2 = r2 % m2;
+            let g = gcd(m1, m2);
+            let diff = r2 as i128 - r1 as i128;
+            if diff % g as i128 != 0 {
+                return Err(CrtError::Inconsistent);
+            }
+
+            let m = lcm(m1, m2).ok_or(CrtError::Overflow)?;
+            // `p * m1 ≡ g (mod m2)`, so `k = diff / g * p` moves `r1` onto
+            // `r2` modulo `m2`, and only matters modulo `m2 / g`.
+            let (_, p, _) = bezout(m1 as i128, m2 as i128);
+            let step = (m2 / g) as i128;
+            let k = (diff / g as i128).rem_euclid(step) as u128 * p.rem_euclid(step) as u128
+                % step as u128;
+            let r = (r1 as u128 + m1 as u128 * k) % m as u128;
+            Ok((r as u64, m))
+        })
+}
+
+/// The largest `r` with `r * r <= n`.
+pub fn isqrt(n: u128) -> u128 {
+    if n < 2 {
+        return n;
+    }
+
+    // Newton's method from above only decreases until it reaches the root.
+    let mut x = 1 << (n.ilog2() / 2 + 1);
+    loop {
+        let next = (x + n / x) / 2;
+        if next >= x {
+            return x;
+        }
+        x = next;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn gcd_and_lcm() {
+        assert_eq!(gcd(12, 18), 6);
+        assert_eq!(gcd(0, 7), 7);
+        assert_eq!(gcd_all([12, 18, 27]), 3);
+        assert_eq!(gcd_all([]), 0);
+
+        assert_eq!(lcm(4, 6), Some(12));
+        assert_eq!(lcm_all([2, 3, 4, 5]), Some(60));
+        assert_eq!(lcm_all([]), Some(1));
+        assert_eq!(lcm(u64::MAX, u64::MAX - 1), None);
+        assert_eq!(lcm_all([1 << 40, 3 << 30, 5 << 20]), Some(15 << 40));
+    }
+
+    #[test]
+    fn extended_gcd_gives_bezout_coefficients() {
+        for (a, b) in [
+            (240, 46),
+            (-240, 46),
+            (7, 0),
+            (0, -7),
+            (17, 5),
+            (i64::MAX, 2),
+        ] {
+            let (g, x, y) = extended_gcd(a, b);
+            assert_eq!(g, gcd(a.unsigned_abs(), b.unsigned_abs()) as i64);
+            assert_eq!(a as i128 * x as i128 + b as i128 * y as i128, g as i128);
+        }
+    }
+
+    #[test]
+    fn modular_inverse() {
+        assert_eq!(mod_inverse(3, 11), Some(4));
+        assert_eq!(mod_inverse(-3, 11), Some(7));
+        assert_eq!(mod_inverse(6, 9), None);
+        assert_eq!(mod_inverse(5, 1), Some(0));
+    }
+
+    #[test]
+    fn crt_with_and_without_coprime_moduli() {
+        assert_eq!(crt([(2, 3), (3, 5), (2, 7)]), Ok((23, 105)));
+        assert_eq!(crt([(2, 4), (4, 6)]), Ok((10, 12)));
+        assert_eq!(crt([(2, 4), (3, 6)]), Err(CrtError::Inconsistent));
+        assert_eq!(crt([(17, 5)]), Ok((2, 5)));
+        assert_eq!(crt([]), Ok((0, 1)));
+        assert_eq!(
+            crt([(1, u64::MAX), (0, u64::MAX - 1)]),
+            Err(CrtError::Overflow)
+        );
+
+        // Brute force over small moduli.
+        for (m1, m2) in [(4, 6), (5, 7), (9, 12), (8, 8)] {
+            for r1 in 0..m1 {
+                for r2 in 0..m2 {
+                    let expected = (0..m1 * m2).find(|x| x % m1 == r1 && x % m2 == r2);
+                    match crt([(r1, m1), (r2, m2)]) {
+                        Ok((r, m)) => {
+                            assert_eq!(Some(r), expected, "{r1} mod {m1}, {r2} mod {m2}");
+                            assert_eq!(m, lcm(m1, m2).unwrap());
+                        }
+                        Err(err) => assert_eq!((err, expected), (CrtError::Inconsistent, None)),
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn isqrt_is_exact() {
+        for n in 0..10_000u128 {
+            let r = isqrt(n);
+            assert!(r * r <= n && (r + 1) * (r + 1) > n, "{n}");
+        }
+        assert_eq!(isqrt(u128::MAX), u64::MAX as u128);
+    }
+}
@@ -0,0 +1,129 @@
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+/// Solver-specific `key=value` settings, given with `--set` on the command
+/// line and read by [`Solution::parse_with`](crate::Solution::parse_with).
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Params {
+    values: BTreeMap<String, String>,
+}
+
+impl Params {
+    pub fn new() -> Params {
+        Params::default()
+    }
+
+    /// Sets `key` to `value`, replacing an earlier value.
+    pub fn insert(&mut self, key: impl Into<String>, value: impl Into<String>) {
+        self.values.insert(key.into(), value.into());
+    }
+
+    /// Adds every setting of `other`, replacing values of the same key.
+    pub fn extend(&mut self, other: Params) {
+        self.values.extend(other.values);
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// The value of `key`, parsed; `None` when it was not given.
+    pub fn get<T: FromStr>(&self, key: &str) -> Result<Option<T>, ParamError> {
+        self.values
+            .get(key)
+            .map(|value| {
+                value.parse().map_err(|_| ParamError::InvalidValue {
+                    key: key.to_string(),
+                    value: value.clone(),
+                })
+            })
+            .transpose()
+    }
+
+    /// Rejects every key that is not in `known`.
+    pub fn check(&self, known: &[&str]) -> Result<(), ParamError> {
+        match self
+            .values
+            .keys()
+            .find(|key| !known.contains(&key.as_str()))
+        {
+            Some(key) => Err(ParamError::Unknown(key.clone())),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Parses a `key=value` pair.
+impl FromStr for Params {
+    type Err = ParamError;
+
+    fn from_str(s: &str) -> Result<Params, ParamError> {
+        match s.split_once('=') {
+            Some((key, value)) if !key.is_empty() => {
+                let mut params = Params::new();
+                params.insert(key, value);
+                Ok(params)
+            }
+            _ => Err(ParamError::Malformed(s.to_string())),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParamError {
+    Malformed(String),
+    Unknown(String),
+    InvalidValue { key: String, value: String },
+}
+
+impl fmt::Display for ParamError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParamError::Malformed(param) => {
+                write!(f, "invalid parameter `{param}`, expected key=value")
+            }
+            ParamError::Unknown(key) => write!(f, "unknown parameter `{key}`"),
+            ParamError::InvalidValue { key, value } => {
+                write!(f, "invalid value `{value}` for parameter `{key}`")
+            }
+        }
+    }
+}
+
+impl Error for ParamError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_key_value_pairs() {
+        let params: Params = "mode=fast".parse().unwrap();
+        assert_eq!(
+            params.get::<String>("mode").unwrap().as_deref(),
+            Some("fast")
+        );
+        assert_eq!(params.get::<String>("other").unwrap(), None);
+
+        assert!("mode".parse::<Params>().is_err());
+        assert!("=fast".parse::<Params>().is_err());
+    }
+
+    #[test]
+    fn typed_values_and_unknown_keys() {
+        let mut params = Params::new();
+        params.insert("factor", "ten");
+
+        assert_eq!(
+            params.get::<u64>("factor").unwrap_err().to_string(),
+            "invalid value `ten` for parameter `factor`"
+        );
+        assert!(params.check(&["factor"]).is_ok());
+        assert_eq!(
+            params.check(&["mode"]).unwrap_err(),
+            ParamError::Unknown("factor".to_string())
+        );
+    }
+}
@@ -0,0 +1,148 @@
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+/// Malformed puzzle input, located by line and column (both starting at 1).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    /// The offending text; empty when something is missing.
+    pub text: String,
+    pub message: String,
+}
+
+impl ParseError {
+    pub fn new(line: usize, column: usize, text: &str, message: impl Into<String>) -> ParseError {
+        ParseError {
+            line,
+            column,
+            text: text.to_string(),
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: {}",
+            self.line, self.column, self.message
+        )?;
+        if !self.text.is_empty() {
+            write!(f, ": `{}`", self.text)?;
+        }
+        Ok(())
+    }
+}
+
+impl Error for ParseError {}
+
+/// One line of input, remembering where it is so errors can point into it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Line<'a> {
+    /// Line number, starting at 1.
+    pub number: usize,
+    pub text: &'a str,
+}
+
+/// The lines of `input`, numbered from 1.
+pub fn lines(input: &str) -> impl Iterator<Item = Line<'_>> {
+    input.lines().enumerate().map(|(index, text)| Line {
+        number: index + 1,
+        text,
+    })
+}
+
+impl<'a> Line<'a> {
+    /// Column (from 1) where `part` starts. `part` should be a slice of this
+    /// line; otherwise its first occurrence is used, or the end of the line.
+    pub fn column(&self, part: &str) -> usize {
+        let start = self.text.as_ptr() as usize;
+        let offset = (part.as_ptr() as usize).wrapping_sub(start);
+        let within = offset
+            .checked_add(part.len())
+            .is_some_and(|end| end <= self.text.len());
+
+        let offset = if within {
+            offset
+        } else {
+            self.text.find(part).unwrap_or(self.text.len())
+        };
+
+        self.text[..offset].chars().count() + 1
+    }
+
+    /// An error pointing at `part` of this line.
+    pub fn error(&self, part: &str, message: impl Into<String>) -> ParseError {
+        ParseError::new(self.number, self.column(part), part, message)
+    }
+
+    /// An error for something missing at the end of this line.
+    pub fn missing(&self, message: impl Into<String>) -> ParseError {
+        let column = self.text.chars().count() + 1;
+        ParseError::new(self.number, column, "", message)
+    }
+
+    /// Parses `part` of this line, trimmed of surrounding whitespace.
+    pub fn parse<T: FromStr>(&self, part: &str) -> Result<T, ParseError> {
+        let part = part.trim();
+        part.parse().map_err(|_| self.error(part, "invalid number"))
+    }
+
+    /// Parses every whitespace-separated number in `part`.
+    pub fn numbers<T: FromStr>(&self, part: &'a str) -> Result<Vec<T>, ParseError> {
+        part.split_whitespace().map(|n| self.parse(n)).collect()
+    }
+
+    /// Splits this line around the first `delimiter`.
+    pub fn split_once(&self, delimiter: &str) -> Result<(&'a str, &'a str), ParseError> {
+        self.text
+            .split_once(delimiter)
+            .ok_or_else(|| self.missing(format!("expected `{delimiter}`")))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lines_are_numbered_from_one() {
+        let numbers: Vec<usize> = lines("a\nb\n\nc").map(|line| line.number).collect();
+        assert_eq!(numbers, [1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn column_of_a_slice() {
+        let line = Line {
+            number: 3,
+            text: "Game 1: 4 red",
+        };
+        let (_, sets) = line.split_once(":").unwrap();
+        assert_eq!(line.column(sets.trim()), 9);
+        assert_eq!(line.column("red"), 11);
+    }
+
+    #[test]
+    fn parse_errors_point_at_the_token() {
+        let line = Line {
+            number: 2,
+            text: "1 2 x3",
+        };
+        let err = line.numbers::<i32>(line.text).unwrap_err();
+        assert_eq!(err, ParseError::new(2, 5, "x3", "invalid number"));
+        assert_eq!(err.to_string(), "line 2, column 5: invalid number: `x3`");
+    }
+
+    #[test]
+    fn missing_delimiter_points_past_the_end() {
+        let line = Line {
+            number: 1,
+            text: "abc",
+        };
+        let err = line.split_once("=").unwrap_err();
+        assert_eq!(err.to_string(), "line 1, column 4: expected `=`");
+    }
+}
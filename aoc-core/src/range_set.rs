@@ -0,0 +1,250 @@
+use std::fmt;
+use std::ops::Range;
+
+/// A set of integers stored as sorted, disjoint, half-open ranges.
+///
+/// The ranges are kept normalized: none is empty, and overlapping or
+/// touching ranges are merged, so two sets holding the same values compare
+/// equal.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct RangeSet {
+    ranges: Vec<Range<i64>>,
+}
+
+impl RangeSet {
+    pub fn new() -> RangeSet {
+        RangeSet::default()
+    }
+
+    /// Sorts and merges `ranges`, dropping empty ones.
+    pub fn normalize(mut ranges: Vec<Range<i64>>) -> RangeSet {
+        ranges.retain(|range| !range.is_empty());
+        ranges.sort_unstable_by_key(|range| range.start);
+
+        let mut merged: Vec<Range<i64>> = Vec::with_capacity(ranges.len());
+        for range in ranges {
+            match merged.last_mut() {
+                Some(last) if range.start <= last.end => last.end = last.end.max(range.end),
+                _ => merged.push(range),
+            }
+        }
+
+        RangeSet { ranges: merged }
+    }
+
+    pub fn ranges(&self) -> &[Range<i64>] {
+        &self.ranges
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// How many values the set holds.
+    pub fn len(&self) -> i64 {
+        self.ranges
+            .iter()
+            .map(|range| range.end - range.start)
+            .sum()
+    }
+
+    pub fn contains(&self, value: i64) -> bool {
+        let index = self.ranges.partition_point(|range| range.end <= value);
+        self.ranges
+            .get(index)
+            .is_some_and(|range| range.contains(&value))
+    }
+
+    pub fn min(&self) -> Option<i64> {
+        self.ranges.first().map(|range| range.start)
+    }
+
+    pub fn max(&self) -> Option<i64> {
+        self.ranges.last().map(|range| range.end - 1)
+    }
+
+    pub fn insert(&mut self, range: Range<i64>) {
+        *self = self.union(&RangeSet::from(range));
+    }
+
+    pub fn union(&self, other: &RangeSet) -> RangeSet {
+        RangeSet::normalize(self.ranges.iter().chain(&other.ranges).cloned().collect())
+    }
+
+    pub fn intersection(&self, other: &RangeSet) -> RangeSet {
+        let mut ranges = Vec::new();
+        let (mut i, mut j) = (0, 0);
+
+        while let (Some(a), Some(b)) = (self.ranges.get(i), other.ranges.get(j)) {
+            let start = a.start.max(b.start);
+            let end = a.end.min(b.end);
+            if start < end {
+                ranges.push(start..end);
+            }
+
+            // Whichever range ends first cannot overlap anything further on.
+            if a.end < b.end {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+
+        RangeSet { ranges }
+    }
+
+    pub fn difference(&self, other: &RangeSet) -> RangeSet {
+        let mut ranges = Vec::new();
+        let mut removed = other.ranges.iter().peekable();
+
+        for range in &self.ranges {
+            let mut start = range.start;
+            while let Some(cut) = removed.peek() {
+                if cut.end <= start {
+                    removed.next();
+                    continue;
+                }
+                if cut.start >= range.end {
+                    break;
+                }
+                if cut.start > start {
+                    ranges.push(start..cut.start);
+                }
+                start = cut.end;
+                if cut.end > range.end {
+                    break;
+                }
+                removed.next();
+            }
+            if start < range.end {
+                ranges.push(start..range.end);
+            }
+        }
+
+        RangeSet { ranges }
+    }
+
+    /// The set with every value moved by `offset`.
+    pub fn shift(&self, offset: i64) -> RangeSet {
+        let ranges = self
+            .ranges
+            .iter()
+            .map(|range| range.start + offset..range.end + offset)
+            .collect();
+
+        RangeSet { ranges }
+    }
+
+    /// Splits the set by `pieces`: the part inside each piece, in order, and
+    /// the remainder that is inside none of them.
+    pub fn split<'a>(
+        &self,
+        pieces: impl IntoIterator<Item = &'a Range<i64>>,
+    ) -> (Vec<RangeSet>, RangeSet) {
+        let mut remainder = self.clone();
+        let parts = pieces
+            .into_iter()
+            .map(|piece| {
+                let piece = RangeSet::from(piece.clone());
+                remainder = remainder.difference(&piece);
+                self.intersection(&piece)
+            })
+            .collect();
+
+        (parts, remainder)
+    }
+}
+
+impl From<Range<i64>> for RangeSet {
+    fn from(range: Range<i64>) -> RangeSet {
+        RangeSet::normalize(vec![range])
+    }
+}
+
+impl FromIterator<Range<i64>> for RangeSet {
+    fn from_iter<I: IntoIterator<Item = Range<i64>>>(ranges: I) -> RangeSet {
+        RangeSet::normalize(ranges.into_iter().collect())
+    }
+}
+
+impl fmt::Display for RangeSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{{")?;
+        for (i, range) in self.ranges.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{}..{}", range.start, range.end)?;
+        }
+        write!(f, "}}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn set(ranges: &[Range<i64>]) -> RangeSet {
+        ranges.iter().cloned().collect()
+    }
+
+    #[test]
+    fn normalizes() {
+        let set = set(&[5..8, 0..2, 1..3, 3..4, 9..9]);
+        assert_eq!(set.ranges(), [0..4, 5..8]);
+        assert_eq!(set.len(), 7);
+        assert_eq!((set.min(), set.max()), (Some(0), Some(7)));
+        assert!(set.contains(3) && !set.contains(4) && !set.contains(8));
+        assert_eq!(set.to_string(), "{0..4, 5..8}");
+    }
+
+    #[test]
+    fn set_operations() {
+        let a = set(&[0..10, 20..30]);
+        let b = set(&[5..25, 28..40]);
+
+        assert_eq!(a.union(&b), RangeSet::from(0..40));
+        assert_eq!(a.intersection(&b).ranges(), [5..10, 20..25, 28..30]);
+        assert_eq!(a.difference(&b).ranges(), [0..5, 25..28]);
+        assert_eq!(b.difference(&a).ranges(), [10..20, 30..40]);
+        assert!(a.difference(&a).is_empty());
+        assert_eq!(a.difference(&RangeSet::new()), a);
+    }
+
+    #[test]
+    fn difference_with_a_cut_inside_one_range() {
+        let a = RangeSet::from(0..10);
+        assert_eq!(
+            a.difference(&set(&[2..3, 5..7])).ranges(),
+            [0..2, 3..5, 7..10]
+        );
+        assert_eq!(
+            set(&[0..3, 5..9])
+                .difference(&RangeSet::from(2..6))
+                .ranges(),
+            [0..2, 6..9]
+        );
+    }
+
+    #[test]
+    fn split_keeps_the_remainder() {
+        let seeds = RangeSet::from(0..10);
+        let (parts, remainder) = seeds.split(&[2..4, 8..15, 20..30]);
+
+        assert_eq!(
+            parts,
+            [RangeSet::from(2..4), RangeSet::from(8..10), RangeSet::new()]
+        );
+        assert_eq!(remainder.ranges(), [0..2, 4..8]);
+        assert_eq!(remainder.shift(100).ranges(), [100..102, 104..108]);
+    }
+
+    #[test]
+    fn insert() {
+        let mut set = RangeSet::new();
+        set.insert(3..5);
+        set.insert(0..1);
+        set.insert(1..3);
+        assert_eq!(set, RangeSet::from(0..5));
+    }
+}
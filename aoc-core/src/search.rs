@@ -0,0 +1,350 @@
+//! Searches over implicit graphs, where the edges of a node come from a
+//! successor closure instead of a stored adjacency list.
+
+use std::cmp::Reverse;
+use std::collections::hash_map::Entry;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::hash::{BuildHasherDefault, Hash, Hasher};
+use std::ops::Add;
+
+// Searches hash every node several times, and puzzle nodes are small and
+// trusted, so a cheap multiplicative hash beats the default SipHash by a lot.
+type Map<K, V> = HashMap<K, V, BuildHasherDefault<NodeHasher>>;
+
+#[derive(Debug, Default)]
+struct NodeHasher(u64);
+
+impl Hasher for NodeHasher {
+    fn finish(&self) -> u64 {
+        self.0
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            self.write_u64(byte.into());
+        }
+    }
+
+    fn write_u64(&mut self, n: u64) {
+        self.0 = (self.0.rotate_left(5) ^ n).wrapping_mul(0x517c_c1b7_2722_0a95);
+    }
+
+    fn write_usize(&mut self, n: usize) {
+        self.write_u64(n as u64);
+    }
+}
+
+/// Everything reached by a search from one start node.
+#[derive(Debug, Clone)]
+pub struct Search<N, C> {
+    distances: Map<N, C>,
+    predecessors: Map<N, N>,
+    order: Vec<N>,
+}
+
+impl<N: Clone + Eq + Hash, C: Copy> Search<N, C> {
+    fn new() -> Search<N, C> {
+        Search {
+            distances: Map::default(),
+            predecessors: Map::default(),
+            order: Vec::new(),
+        }
+    }
+
+    pub fn contains(&self, node: &N) -> bool {
+        self.distances.contains_key(node)
+    }
+
+    /// Cost of the path found to `node`, if it was reached.
+    pub fn distance(&self, node: &N) -> Option<C> {
+        self.distances.get(node).copied()
+    }
+
+    /// Every reached node with its distance, in no particular order.
+    pub fn distances(&self) -> impl Iterator<Item = (&N, C)> {
+        self.distances
+            .iter()
+            .map(|(node, &distance)| (node, distance))
+    }
+
+    /// The node `node` was reached from; `None` for the start and for nodes
+    /// that were not reached.
+    pub fn predecessor(&self, node: &N) -> Option<&N> {
+        self.predecessors.get(node)
+    }
+
+    /// Every reached node but the start with its predecessor, in no
+    /// particular order.
+    pub fn predecessors(&self) -> impl Iterator<Item = (&N, &N)> {
+        self.predecessors.iter()
+    }
+
+    /// Reached nodes in the order they were settled, starting with the start.
+    pub fn order(&self) -> &[N] {
+        &self.order
+    }
+
+    /// The path from the start to `node`, both included.
+    pub fn path_to(&self, node: &N) -> Option<Vec<N>> {
+        if !self.contains(node) {
+            return None;
+        }
+
+        let mut path = vec![node.clone()];
+        while let Some(previous) = self.predecessor(path.last().unwrap()) {
+            path.push(previous.clone());
+        }
+        path.reverse();
+
+        Some(path)
+    }
+}
+
+/// Breadth-first search; distances count edges.
+pub fn bfs<N, I>(start: N, mut successors: impl FnMut(&N) -> I) -> Search<N, usize>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut search = Search::new();
+    let mut queue = VecDeque::from([start.clone()]);
+    search.distances.insert(start, 0);
+
+    while let Some(node) = queue.pop_front() {
+        let distance = search.distances[&node];
+        for next in successors(&node) {
+            if let Entry::Vacant(entry) = search.distances.entry(next.clone()) {
+                entry.insert(distance + 1);
+                search.predecessors.insert(next.clone(), node.clone());
+                queue.push_back(next);
+            }
+        }
+        search.order.push(node);
+    }
+
+    search
+}
+
+/// Depth-first search; `order` is the preorder and distances are depths in
+/// the search tree, not shortest paths. Successors are explored last first.
+pub fn dfs<N, I>(start: N, mut successors: impl FnMut(&N) -> I) -> Search<N, usize>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut search = Search::new();
+    let mut stack = vec![(start, None, 0)];
+
+    while let Some((node, predecessor, depth)) = stack.pop() {
+        if search.contains(&node) {
+            continue;
+        }
+
+        search.distances.insert(node.clone(), depth);
+        if let Some(predecessor) = predecessor {
+            search.predecessors.insert(node.clone(), predecessor);
+        }
+
+        for next in successors(&node) {
+            if !search.contains(&next) {
+                stack.push((next, Some(node.clone()), depth + 1));
+            }
+        }
+        search.order.push(node);
+    }
+
+    search
+}
+
+/// Dijkstra's algorithm over non-negative edge costs. `C::default()` is the
+/// cost of the start.
+pub fn dijkstra<N, C, I>(start: N, mut successors: impl FnMut(&N) -> I) -> Search<N, C>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut search = Search::new();
+    let mut best = Map::default();
+    best.insert(start.clone(), C::default());
+    let mut nodes = vec![start];
+    let mut heap = BinaryHeap::from([Reverse((C::default(), 0))]);
+
+    while let Some(Reverse((cost, index))) = heap.pop() {
+        let node = nodes[index].clone();
+        if search.contains(&node) || best[&node] < cost {
+            continue;
+        }
+
+        search.distances.insert(node.clone(), cost);
+        for (next, step) in successors(&node) {
+            let next_cost = cost + step;
+            if search.contains(&next) || best.get(&next).is_some_and(|&c| c <= next_cost) {
+                continue;
+            }
+            best.insert(next.clone(), next_cost);
+            search.predecessors.insert(next.clone(), node.clone());
+            heap.push(Reverse((next_cost, nodes.len())));
+            nodes.push(next);
+        }
+        search.order.push(node);
+    }
+
+    search
+}
+
+/// A* search from `start` to the first node satisfying `goal`. Returns the
+/// path, both ends included, and its cost.
+///
+/// `heuristic` must be consistent: 0 at goals, and never more than the cost
+/// of a step plus the heuristic of the node it reaches. Settled nodes are
+/// never reopened, so a heuristic that is only admissible can settle a node
+/// through a longer path and return a path that is not the shortest.
+pub fn astar<N, C, I>(
+    start: N,
+    mut successors: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> C,
+    mut goal: impl FnMut(&N) -> bool,
+) -> Option<(Vec<N>, C)>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut search = Search::new();
+    let mut best = Map::default();
+    best.insert(start.clone(), C::default());
+    let mut heap = BinaryHeap::from([Reverse((heuristic(&start), C::default(), 0))]);
+    let mut nodes = vec![start];
+
+    while let Some(Reverse((_, cost, index))) = heap.pop() {
+        let node = nodes[index].clone();
+        if search.contains(&node) || best[&node] < cost {
+            continue;
+        }
+
+        search.distances.insert(node.clone(), cost);
+        if goal(&node) {
+            return Some((search.path_to(&node)?, cost));
+        }
+
+        for (next, step) in successors(&node) {
+            let next_cost = cost + step;
+            if search.contains(&next) || best.get(&next).is_some_and(|&c| c <= next_cost) {
+                continue;
+            }
+            best.insert(next.clone(), next_cost);
+            search.predecessors.insert(next.clone(), node.clone());
+            heap.push(Reverse((
+                next_cost + heuristic(&next),
+                next_cost,
+                nodes.len(),
+            )));
+            nodes.push(next);
+        }
+        search.order.push(node);
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Grid, Pos};
+
+    // Walls are `#`; entering a digit costs that digit, anything else 1.
+    const MAZE: &str = "\
+.9..#.
+.##.#.
+....#.";
+
+    fn maze() -> Grid<char> {
+        Grid::parse(MAZE, Some).unwrap()
+    }
+
+    fn open(grid: &Grid<char>, pos: Pos) -> Vec<Pos> {
+        grid.neighbors4(pos).filter(|&p| grid[p] != '#').collect()
+    }
+
+    fn weighted(grid: &Grid<char>, pos: Pos) -> Vec<(Pos, u32)> {
+        open(grid, pos)
+            .into_iter()
+            .map(|p| (p, grid[p].to_digit(10).unwrap_or(1)))
+            .collect()
+    }
+
+    fn manhattan(a: Pos, b: Pos) -> u32 {
+        (a.0.abs_diff(b.0) + a.1.abs_diff(b.1)) as u32
+    }
+
+    #[test]
+    fn bfs_counts_edges() {
+        let grid = maze();
+        let search = bfs((0, 0), |&pos| open(&grid, pos));
+
+        assert_eq!(search.distance(&(0, 0)), Some(0));
+        assert_eq!(search.distance(&(0, 2)), Some(2));
+        assert_eq!(search.distance(&(2, 3)), Some(5));
+        assert_eq!(search.distance(&(0, 5)), None);
+        assert_eq!(search.order()[0], (0, 0));
+        assert_eq!(search.order().len(), search.distances().count());
+    }
+
+    #[test]
+    fn paths_follow_predecessors() {
+        let grid = maze();
+        let search = bfs((0, 0), |&pos| open(&grid, pos));
+
+        let path = search.path_to(&(2, 2)).unwrap();
+        assert_eq!(path, [(0, 0), (1, 0), (2, 0), (2, 1), (2, 2)]);
+        assert_eq!(search.predecessor(&(0, 0)), None);
+        assert_eq!(search.path_to(&(0, 0)).unwrap(), [(0, 0)]);
+        assert_eq!(search.path_to(&(0, 5)), None);
+    }
+
+    #[test]
+    fn dfs_walks_a_cycle_in_order() {
+        let cycle = |&n: &u32| [(n + 1) % 6, (n + 5) % 6];
+        let search = dfs(0, cycle);
+
+        assert_eq!(search.order(), [0, 5, 4, 3, 2, 1]);
+        assert_eq!(search.distance(&1), Some(5));
+        assert_eq!(search.path_to(&3).unwrap(), [0, 5, 4, 3]);
+    }
+
+    #[test]
+    fn dijkstra_avoids_expensive_cells() {
+        let grid = maze();
+        let search = dijkstra((0, 0), |&pos| weighted(&grid, pos));
+
+        // Straight through the 9 would cost 10; around it is 8.
+        assert_eq!(search.distance(&(0, 2)), Some(8));
+        assert!(!search.path_to(&(0, 2)).unwrap().contains(&(0, 1)));
+        assert_eq!(search.distance(&(0, 1)), Some(9));
+        assert_eq!(search.distance(&(0, 5)), None);
+    }
+
+    #[test]
+    fn astar_agrees_with_dijkstra() {
+        let grid = maze();
+        let search = dijkstra((0, 0), |&pos| weighted(&grid, pos));
+
+        for goal in grid.positions(|&c| c != '#') {
+            let found = astar(
+                (0, 0),
+                |&pos| weighted(&grid, pos),
+                |&pos| manhattan(pos, goal),
+                |&pos| pos == goal,
+            );
+            match search.distance(&goal) {
+                Some(distance) => {
+                    let (path, cost) = found.unwrap();
+                    assert_eq!(cost, distance);
+                    assert_eq!((path[0], *path.last().unwrap()), ((0, 0), goal));
+                }
+                None => assert!(found.is_none()),
+            }
+        }
+    }
+}
@@ -0,0 +1,181 @@
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::fmt::{self, Display};
+use std::hint::black_box;
+use std::marker::PhantomData;
+use std::time::{Duration, Instant};
+
+use crate::{Params, Part};
+
+/// A day's puzzle: the input is parsed once into a `Model` that both parts
+/// then solve from.
+pub trait Solution {
+    const YEAR: u32;
+    const DAY: u32;
+
+    type Model;
+    type Answer1: Display;
+    type Answer2: Display;
+
+    fn parse(input: &str) -> Result<Self::Model, Box<dyn Error>>;
+
+    /// Parses `input` with solver-specific `params`. Days that take no
+    /// parameters keep this default, which rejects any.
+    fn parse_with(input: &str, params: &Params) -> Result<Self::Model, Box<dyn Error>> {
+        params.check(&[])?;
+        Self::parse(input)
+    }
+
+    /// Writes the files `params` ask for, such as a report on `model`. The
+    /// runner calls this once per `aoc run`, after parsing and before
+    /// solving, so exports never wait on the solvers.
+    fn export(_model: &Self::Model, _params: &Params) -> Result<(), Box<dyn Error>> {
+        Ok(())
+    }
+
+    fn part1(model: &Self::Model) -> Self::Answer1;
+
+    fn part2(model: &Self::Model) -> Self::Answer2;
+
+    /// The published example input for `part`, if the day embeds one.
+    fn example(_part: Part) -> Option<&'static str> {
+        None
+    }
+}
+
+/// Answer type for a part that has no solution yet.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Unsolved;
+
+impl Display for Unsolved {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unsolved")
+    }
+}
+
+/// The displayed answer of one part.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Answer {
+    pub part: Part,
+    pub value: String,
+    /// Time spent solving this part, not counting the shared parse.
+    pub elapsed: Duration,
+}
+
+/// How long one parse and one solve of a part took.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Timing {
+    pub parse: Duration,
+    pub solve: Duration,
+}
+
+/// Object-safe view of a [`Solution`], so days with different models and
+/// answer types can live in one [`Registry`].
+pub trait Runnable {
+    fn year(&self) -> u32;
+
+    fn day(&self) -> u32;
+
+    fn example(&self, part: Part) -> Option<&'static str>;
+
+    /// Parses `input` once with `params` and solves each of `parts` from the
+    /// same model, first writing the day's exports if `export` is set.
+    fn run(
+        &self,
+        input: &str,
+        parts: &[Part],
+        params: &Params,
+        export: bool,
+    ) -> Result<Vec<Answer>, Box<dyn Error>>;
+
+    /// Parses `input` and solves `part`, timing each phase.
+    fn time(&self, input: &str, part: Part) -> Result<Timing, Box<dyn Error>>;
+}
+
+struct Registered<S>(PhantomData<fn() -> S>);
+
+impl<S: Solution> Runnable for Registered<S> {
+    fn year(&self) -> u32 {
+        S::YEAR
+    }
+
+    fn day(&self) -> u32 {
+        S::DAY
+    }
+
+    fn example(&self, part: Part) -> Option<&'static str> {
+        S::example(part)
+    }
+
+    fn run(
+        &self,
+        input: &str,
+        parts: &[Part],
+        params: &Params,
+        export: bool,
+    ) -> Result<Vec<Answer>, Box<dyn Error>> {
+        let model = S::parse_with(input, params)?;
+        if export {
+            S::export(&model, params)?;
+        }
+
+        let answers = parts
+            .iter()
+            .map(|&part| {
+                let start = Instant::now();
+                let value = match part {
+                    Part::One => S::part1(&model).to_string(),
+                    Part::Two => S::part2(&model).to_string(),
+                };
+                Answer {
+                    part,
+                    value,
+                    elapsed: start.elapsed(),
+                }
+            })
+            .collect();
+
+        Ok(answers)
+    }
+
+    fn time(&self, input: &str, part: Part) -> Result<Timing, Box<dyn Error>> {
+        let start = Instant::now();
+        let model = S::parse(input)?;
+        let parse = start.elapsed();
+
+        let start = Instant::now();
+        match part {
+            Part::One => drop(black_box(S::part1(&model))),
+            Part::Two => drop(black_box(S::part2(&model))),
+        }
+        let solve = start.elapsed();
+
+        Ok(Timing { parse, solve })
+    }
+}
+
+/// Every known solution, keyed by `(year, day)`.
+#[derive(Default)]
+pub struct Registry {
+    solutions: BTreeMap<(u32, u32), Box<dyn Runnable>>,
+}
+
+impl Registry {
+    pub fn new() -> Registry {
+        Registry::default()
+    }
+
+    pub fn register<S: Solution + 'static>(&mut self) {
+        let solution = Registered::<S>(PhantomData);
+        self.solutions.insert((S::YEAR, S::DAY), Box::new(solution));
+    }
+
+    pub fn get(&self, year: u32, day: u32) -> Option<&dyn Runnable> {
+        self.solutions.get(&(year, day)).map(|solution| &**solution)
+    }
+
+    /// Registered solutions ordered by year, then day.
+    pub fn iter(&self) -> impl Iterator<Item = &dyn Runnable> {
+        self.solutions.values().map(|solution| &**solution)
+    }
+}
@@ -0,0 +1,134 @@
+//! Opt-in pausing at named checkpoints inside solvers, for `aoc run --step`.
+//!
+//! Solvers call [`checkpoint`] wherever their state is worth a look. Until a
+//! [`Stepper`] is installed a checkpoint is one atomic load, so a normal run
+//! never waits on anything.
+
+use std::collections::HashSet;
+use std::process;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+
+/// Exit status when the user quits at a checkpoint.
+pub const QUIT_STATUS: i32 = 130;
+
+static ACTIVE: AtomicBool = AtomicBool::new(false);
+static SESSION: Mutex<Option<Session>> = Mutex::new(None);
+
+/// What to do after pausing at a checkpoint.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Action {
+    /// Run until the next checkpoint.
+    Continue,
+    /// Stop pausing at this checkpoint, but keep pausing at the others.
+    Skip,
+    /// Stop the whole process.
+    Quit,
+}
+
+/// Decides, with the user, how to go on from a checkpoint.
+pub trait Stepper {
+    fn pause(&mut self, checkpoint: &str, state: &str) -> Action;
+}
+
+struct Session {
+    stepper: Box<dyn Stepper + Send>,
+    skipped: HashSet<&'static str>,
+}
+
+/// Makes every following checkpoint pause with `stepper`.
+pub fn install(stepper: impl Stepper + Send + 'static) {
+    *SESSION.lock().unwrap_or_else(|err| err.into_inner()) = Some(Session {
+        stepper: Box::new(stepper),
+        skipped: HashSet::new(),
+    });
+    ACTIVE.store(true, Ordering::Release);
+}
+
+/// Removes the installed stepper; checkpoints no longer pause.
+pub fn uninstall() {
+    ACTIVE.store(false, Ordering::Release);
+    SESSION.lock().unwrap_or_else(|err| err.into_inner()).take();
+}
+
+/// Whether a stepper is installed, for solvers that do extra work only to
+/// have something to show at their checkpoints.
+pub fn active() -> bool {
+    ACTIVE.load(Ordering::Acquire)
+}
+
+/// Pauses at the checkpoint `name` when stepping, showing `state()`. Solvers
+/// running on several threads wait for each other here.
+pub fn checkpoint(name: &'static str, state: impl FnOnce() -> String) {
+    if !active() {
+        return;
+    }
+
+    let mut session = SESSION.lock().unwrap_or_else(|err| err.into_inner());
+    let Some(session) = session.as_mut() else {
+        return;
+    };
+    if session.skipped.contains(name) {
+        return;
+    }
+
+    match session.stepper.pause(name, &state()) {
+        Action::Continue => {}
+        Action::Skip => {
+            session.skipped.insert(name);
+        }
+        Action::Quit => process::exit(QUIT_STATUS),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+
+    struct Scripted {
+        actions: Vec<Action>,
+        seen: Arc<Mutex<Vec<String>>>,
+    }
+
+    impl Stepper for Scripted {
+        fn pause(&mut self, checkpoint: &str, state: &str) -> Action {
+            self.seen
+                .lock()
+                .unwrap()
+                .push(format!("{checkpoint}: {state}"));
+            self.actions.remove(0)
+        }
+    }
+
+    #[test]
+    fn pauses_until_skipped_or_uninstalled() {
+        checkpoint("ignored", || unreachable!("not stepping"));
+        assert!(!active());
+
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        install(Scripted {
+            actions: vec![
+                Action::Continue,
+                Action::Skip,
+                Action::Continue,
+                Action::Continue,
+            ],
+            seen: Arc::clone(&seen),
+        });
+        assert!(active());
+
+        for i in 0..3 {
+            checkpoint("loop", || format!("i={i}"));
+            checkpoint("end", || "done".to_string());
+        }
+        uninstall();
+        assert!(!active());
+        checkpoint("end", || unreachable!("uninstalled"));
+
+        assert_eq!(
+            *seen.lock().unwrap(),
+            ["loop: i=0", "end: done", "loop: i=1", "loop: i=2"]
+        );
+    }
+}
@@ -0,0 +1,175 @@
+use aoc_core::Part;
+use serde::{Deserialize, Serialize};
+use std::error::Error;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+/// Default location of the answers store, relative to the workspace root.
+pub const ANSWERS_FILE: &str = "answers.toml";
+
+/// Known-good answers, one entry per year, day and input.
+///
+/// ```toml
+/// [[answer]]
+/// year = 2023
+/// day = 1
+/// input = "inputs/2023/01.txt"
+/// part1 = "54597"
+/// part2 = "54504"
+/// ```
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct Answers {
+    #[serde(default, rename = "answer")]
+    entries: Vec<Entry>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct Entry {
+    year: u32,
+    day: u32,
+    input: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    part1: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    part2: Option<String>,
+}
+
+impl Entry {
+    fn part(&self, part: Part) -> Option<&String> {
+        match part {
+            Part::One => self.part1.as_ref(),
+            Part::Two => self.part2.as_ref(),
+        }
+    }
+
+    fn part_mut(&mut self, part: Part) -> &mut Option<String> {
+        match part {
+            Part::One => &mut self.part1,
+            Part::Two => &mut self.part2,
+        }
+    }
+}
+
+impl Answers {
+    /// Loads the store at `path`; a missing file is an empty store.
+    pub fn load(path: &Path) -> Result<Answers, Box<dyn Error>> {
+        match fs::read_to_string(path) {
+            Ok(text) => toml::from_str(&text)
+                .map_err(|err| format!("invalid answers file {}: {err}", path.display()).into()),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Answers::default()),
+            Err(err) => Err(format!("could not read {}: {err}", path.display()).into()),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), Box<dyn Error>> {
+        fs::write(path, toml::to_string(self)?)?;
+        Ok(())
+    }
+
+    /// The recorded answer for one part of a day run against `input`.
+    pub fn get(&self, year: u32, day: u32, input: &str, part: Part) -> Option<&str> {
+        self.entries
+            .iter()
+            .find(|entry| entry.year == year && entry.day == day && entry.input == input)
+            .and_then(|entry| entry.part(part))
+            .map(String::as_str)
+    }
+
+    /// Records `answer`, replacing any previous answer for the same key.
+    pub fn insert(&mut self, year: u32, day: u32, input: &str, part: Part, answer: String) {
+        let index = self
+            .entries
+            .iter()
+            .position(|entry| entry.year == year && entry.day == day && entry.input == input)
+            .unwrap_or_else(|| {
+                self.entries.push(Entry {
+                    year,
+                    day,
+                    input: input.to_string(),
+                    part1: None,
+                    part2: None,
+                });
+                self.entries.len() - 1
+            });
+
+        *self.entries[index].part_mut(part) = Some(answer);
+        self.entries
+            .sort_by(|a, b| (a.year, a.day, &a.input).cmp(&(b.year, b.day, &b.input)));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn temp_file(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("aoc_{name}_{}.toml", std::process::id()))
+    }
+
+    #[test]
+    fn save_and_load_round_trip() {
+        let mut answers = Answers::default();
+        answers.insert(2023, 8, "example", Part::Two, "6".to_string());
+        answers.insert(
+            2023,
+            1,
+            "inputs/2023/01.txt",
+            Part::One,
+            "54597".to_string(),
+        );
+        answers.insert(
+            2023,
+            1,
+            "inputs/2023/01.txt",
+            Part::One,
+            "54598".to_string(),
+        );
+
+        let path = temp_file("round_trip");
+        answers.save(&path).unwrap();
+        let text = fs::read_to_string(&path).unwrap();
+        let loaded = Answers::load(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+
+        // Entries are sorted by day, and a part with no answer is left out.
+        assert!(text.find("day = 1").unwrap() < text.find("day = 8").unwrap());
+        assert_eq!(
+            (text.matches("part1").count(), text.matches("part2").count()),
+            (1, 1)
+        );
+
+        assert_eq!(
+            loaded.get(2023, 1, "inputs/2023/01.txt", Part::One),
+            Some("54598")
+        );
+        assert_eq!(loaded.get(2023, 1, "inputs/2023/01.txt", Part::Two), None);
+        assert_eq!(loaded.get(2023, 8, "example", Part::Two), Some("6"));
+        assert_eq!(loaded.get(2023, 8, "inputs/2023/08.txt", Part::Two), None);
+    }
+
+    #[test]
+    fn missing_file_is_empty() {
+        let answers = Answers::load(&temp_file("missing")).unwrap();
+        assert!(answers.entries.is_empty());
+    }
+
+    #[test]
+    fn malformed_entry_is_an_error() {
+        let path = temp_file("malformed");
+        fs::write(
+            &path,
+            "[[answer]]\nyear = 2023\nday = \"one\"\ninput = \"example\"\n",
+        )
+        .unwrap();
+        let err = Answers::load(&path).unwrap_err();
+        fs::remove_file(&path).unwrap();
+
+        let message = err.to_string();
+        assert!(
+            message.starts_with(&format!("invalid answers file {}:", path.display())),
+            "{message}"
+        );
+    }
+}
@@ -0,0 +1,390 @@
+use aoc_core::{ConfigError, InputSource, Part, Registry, Timing};
+use serde::{Deserialize, Serialize};
+use std::error::Error;
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+const DEFAULT_RUNS: usize = 10;
+const DEFAULT_THRESHOLD: f64 = 20.0;
+
+/// Options for the `bench` command.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BenchConfig {
+    pub day: Option<u32>,
+    pub part: Option<Part>,
+    pub runs: usize,
+    /// Where to write the medians of this run.
+    pub save: Option<PathBuf>,
+    /// Medians of an earlier run to compare against.
+    pub baseline: Option<PathBuf>,
+    /// How many percent slower than the baseline counts as a regression.
+    pub threshold: f64,
+}
+
+impl BenchConfig {
+    /// Builds a config from the arguments that follow `bench`:
+    /// `[day] [part] [-n <runs>] [--save <file>] [--baseline <file>] [--threshold <percent>]`.
+    pub fn build(args: &[String]) -> Result<BenchConfig, ConfigError> {
+        let mut config = BenchConfig {
+            day: None,
+            part: None,
+            runs: DEFAULT_RUNS,
+            save: None,
+            baseline: None,
+            threshold: DEFAULT_THRESHOLD,
+        };
+        let mut positionals = Vec::new();
+        let mut args = args.iter();
+
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "-n" | "--runs" => {
+                    let value = args.next().ok_or(ConfigError::MissingValue("--runs"))?;
+                    config.runs = match value.parse() {
+                        Ok(runs) if runs > 0 => runs,
+                        _ => return Err(ConfigError::InvalidValue("--runs", value.to_string())),
+                    };
+                }
+                "--save" => {
+                    let value = args.next().ok_or(ConfigError::MissingValue("--save"))?;
+                    config.save = Some(PathBuf::from(value));
+                }
+                "--baseline" => {
+                    let value = args.next().ok_or(ConfigError::MissingValue("--baseline"))?;
+                    config.baseline = Some(PathBuf::from(value));
+                }
+                "--threshold" => {
+                    let value = args
+                        .next()
+                        .ok_or(ConfigError::MissingValue("--threshold"))?;
+                    config.threshold = match value.parse::<f64>() {
+                        Ok(threshold) if threshold.is_finite() => threshold,
+                        _ => {
+                            return Err(ConfigError::InvalidValue("--threshold", value.to_string()))
+                        }
+                    };
+                }
+                flag if flag.starts_with('-') => {
+                    return Err(ConfigError::UnknownFlag(flag.to_string()));
+                }
+                positional => positionals.push(positional),
+            }
+        }
+
+        match positionals[..] {
+            [] => {}
+            [day] => config.day = Some(parse_day(day)?),
+            [day, part] => {
+                config.day = Some(parse_day(day)?);
+                config.part = Some(part.parse()?);
+            }
+            [_, _, extra, ..] => {
+                return Err(ConfigError::UnexpectedArgument(extra.to_string()));
+            }
+        }
+
+        Ok(config)
+    }
+}
+
+fn parse_day(day: &str) -> Result<u32, ConfigError> {
+    day.parse()
+        .map_err(|_| ConfigError::InvalidValue("day", day.to_string()))
+}
+
+/// Minimum, median and maximum of a set of runs. The median of an even
+/// number of runs is the slower of the two middle ones.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    fn new(mut samples: Vec<Duration>) -> Stats {
+        samples.sort();
+        Stats {
+            min: samples[0],
+            median: samples[samples.len() / 2],
+            max: samples[samples.len() - 1],
+        }
+    }
+}
+
+impl fmt::Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:.1?} / {:.1?} / {:.1?}",
+            self.min, self.median, self.max
+        )
+    }
+}
+
+/// Timings of one part of one day over every run.
+#[derive(Debug, Clone)]
+pub struct Bench {
+    pub year: u32,
+    pub day: u32,
+    pub part: Part,
+    pub parse: Stats,
+    pub solve: Stats,
+}
+
+impl Bench {
+    pub fn total(&self) -> Duration {
+        self.parse.median + self.solve.median
+    }
+}
+
+impl fmt::Display for Bench {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} day {:>2} part {}  parse {}  solve {}",
+            self.year, self.day, self.part, self.parse, self.solve
+        )
+    }
+}
+
+/// Runs every selected day and part `config.runs` times on its puzzle input.
+pub fn bench(registry: &Registry, config: &BenchConfig) -> Result<Vec<Bench>, Box<dyn Error>> {
+    let parts = match config.part {
+        Some(part) => vec![part],
+        None => Part::ALL.to_vec(),
+    };
+    let mut benches = Vec::new();
+
+    let solutions = registry
+        .iter()
+        .filter(|solution| config.day.is_none_or(|day| solution.day() == day));
+
+    for solution in solutions {
+        let input = InputSource::puzzle(solution.year(), solution.day()).read()?;
+
+        for &part in &parts {
+            let timings = (0..config.runs)
+                .map(|_| solution.time(&input, part))
+                .collect::<Result<Vec<Timing>, _>>()?;
+
+            benches.push(Bench {
+                year: solution.year(),
+                day: solution.day(),
+                part,
+                parse: Stats::new(timings.iter().map(|timing| timing.parse).collect()),
+                solve: Stats::new(timings.iter().map(|timing| timing.solve).collect()),
+            });
+        }
+    }
+
+    Ok(benches)
+}
+
+/// Median timings saved by `--save`, in nanoseconds.
+///
+/// ```toml
+/// [[bench]]
+/// year = 2023
+/// day = 1
+/// part = 1
+/// parse_ns = 81234
+/// solve_ns = 170533
+/// ```
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct Baseline {
+    #[serde(default, rename = "bench")]
+    entries: Vec<BaselineEntry>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct BaselineEntry {
+    year: u32,
+    day: u32,
+    part: u32,
+    parse_ns: u64,
+    solve_ns: u64,
+}
+
+impl Baseline {
+    pub fn new(benches: &[Bench]) -> Baseline {
+        let entries = benches
+            .iter()
+            .map(|bench| BaselineEntry {
+                year: bench.year,
+                day: bench.day,
+                part: bench.part.number(),
+                parse_ns: nanos(bench.parse.median),
+                solve_ns: nanos(bench.solve.median),
+            })
+            .collect();
+
+        Baseline { entries }
+    }
+
+    pub fn load(path: &Path) -> Result<Baseline, Box<dyn Error>> {
+        let text = fs::read_to_string(path)
+            .map_err(|err| format!("could not read {}: {err}", path.display()))?;
+        toml::from_str(&text)
+            .map_err(|err| format!("invalid baseline {}: {err}", path.display()).into())
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), Box<dyn Error>> {
+        fs::write(path, toml::to_string(self)?)?;
+        Ok(())
+    }
+
+    /// The baseline's median parse + solve time for the same day and part.
+    pub fn total(&self, bench: &Bench) -> Option<Duration> {
+        self.entries
+            .iter()
+            .find(|entry| {
+                (entry.year, entry.day, entry.part) == (bench.year, bench.day, bench.part.number())
+            })
+            .map(|entry| Duration::from_nanos(entry.parse_ns + entry.solve_ns))
+    }
+}
+
+fn nanos(duration: Duration) -> u64 {
+    duration.as_nanos().try_into().unwrap_or(u64::MAX)
+}
+
+/// Percent change of `current` relative to `baseline`; positive is slower.
+/// Anything slower than a zero baseline is an infinite slowdown.
+pub fn change(baseline: Duration, current: Duration) -> f64 {
+    if baseline.is_zero() {
+        return if current.is_zero() {
+            0.0
+        } else {
+            f64::INFINITY
+        };
+    }
+    (current.as_secs_f64() / baseline.as_secs_f64() - 1.0) * 100.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn build(args: &[&str]) -> Result<BenchConfig, ConfigError> {
+        let args: Vec<String> = args.iter().map(|arg| arg.to_string()).collect();
+        BenchConfig::build(&args)
+    }
+
+    fn ms(ms: u64) -> Duration {
+        Duration::from_millis(ms)
+    }
+
+    #[test]
+    fn flags() {
+        let config = build(&[]).unwrap();
+        assert_eq!((config.day, config.part), (None, None));
+        assert_eq!(config.runs, DEFAULT_RUNS);
+        assert_eq!(config.threshold, DEFAULT_THRESHOLD);
+
+        let config = build(&[
+            "8",
+            "2",
+            "-n",
+            "3",
+            "--save",
+            "new.toml",
+            "--baseline",
+            "old.toml",
+            "--threshold",
+            "5.5",
+        ])
+        .unwrap();
+        assert_eq!((config.day, config.part), (Some(8), Some(Part::Two)));
+        assert_eq!(config.runs, 3);
+        assert_eq!(config.save, Some(PathBuf::from("new.toml")));
+        assert_eq!(config.baseline, Some(PathBuf::from("old.toml")));
+        assert_eq!(config.threshold, 5.5);
+    }
+
+    #[test]
+    fn rejects_bad_arguments() {
+        assert!(matches!(
+            build(&["-n", "0"]),
+            Err(ConfigError::InvalidValue("--runs", value)) if value == "0"
+        ));
+        assert!(matches!(
+            build(&["--runs"]),
+            Err(ConfigError::MissingValue("--runs"))
+        ));
+        for threshold in ["fast", "NaN", "inf"] {
+            assert!(matches!(
+                build(&["--threshold", threshold]),
+                Err(ConfigError::InvalidValue("--threshold", value)) if value == threshold
+            ));
+        }
+        assert!(matches!(
+            build(&["x"]),
+            Err(ConfigError::InvalidValue("day", _))
+        ));
+        assert!(matches!(
+            build(&["8", "3"]),
+            Err(ConfigError::InvalidPart(_))
+        ));
+        assert!(matches!(
+            build(&["8", "1", "extra"]),
+            Err(ConfigError::UnexpectedArgument(arg)) if arg == "extra"
+        ));
+        assert!(matches!(
+            build(&["--quick"]),
+            Err(ConfigError::UnknownFlag(_))
+        ));
+    }
+
+    #[test]
+    fn stats_median() {
+        let stats = Stats::new(vec![ms(5), ms(1), ms(3)]);
+        assert_eq!((stats.min, stats.median, stats.max), (ms(1), ms(3), ms(5)));
+
+        let stats = Stats::new(vec![ms(4), ms(1), ms(3), ms(2)]);
+        assert_eq!((stats.min, stats.median, stats.max), (ms(1), ms(3), ms(4)));
+
+        let stats = Stats::new(vec![ms(7)]);
+        assert_eq!((stats.min, stats.median, stats.max), (ms(7), ms(7), ms(7)));
+    }
+
+    #[test]
+    fn baseline_round_trip() {
+        let bench = |day, part, parse, solve| Bench {
+            year: 2023,
+            day,
+            part,
+            parse: Stats::new(vec![ms(parse)]),
+            solve: Stats::new(vec![ms(solve)]),
+        };
+        let benches = [bench(1, Part::One, 1, 2), bench(1, Part::Two, 1, 5)];
+
+        let path = std::env::temp_dir().join(format!("aoc_baseline_{}.toml", std::process::id()));
+        Baseline::new(&benches).save(&path).unwrap();
+        let baseline = Baseline::load(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(baseline.total(&benches[0]), Some(ms(3)));
+        assert_eq!(baseline.total(&benches[1]), Some(ms(6)));
+        assert_eq!(baseline.total(&bench(2, Part::One, 1, 2)), None);
+    }
+
+    #[test]
+    fn missing_baseline_is_an_error() {
+        let path =
+            std::env::temp_dir().join(format!("aoc_no_baseline_{}.toml", std::process::id()));
+        let err = Baseline::load(&path).unwrap_err();
+        assert!(err.to_string().starts_with("could not read"), "{err}");
+    }
+
+    #[test]
+    fn percent_change() {
+        assert_eq!(change(ms(10), ms(15)), 50.0);
+        assert_eq!(change(ms(10), ms(5)), -50.0);
+        assert_eq!(change(Duration::ZERO, Duration::ZERO), 0.0);
+        assert_eq!(change(Duration::ZERO, ms(1)), f64::INFINITY);
+        assert_eq!(change(ms(1), Duration::ZERO), -100.0);
+    }
+}
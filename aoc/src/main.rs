@@ -0,0 +1,368 @@
+mod answers;
+mod bench;
+mod verify;
+
+use answers::Answers;
+use aoc_core::step::{self, Action, Stepper};
+use aoc_core::{input_hash, Answer, Config, InputSource, OutputFormat, Part, Registry};
+use bench::{Baseline, BenchConfig};
+use serde::Serialize;
+use std::env;
+use std::error::Error;
+use std::fmt;
+use std::io::{self, BufRead, IsTerminal, Write};
+use std::process;
+use std::time::Duration;
+use tracing::info;
+use tracing_subscriber::EnvFilter;
+use verify::{Status, VerifyConfig};
+
+const USAGE: &str = "usage:
+    aoc run <day> [part] [input | --example] [-v...] [--format text|json]
+            [--set key=value...] [--step]
+    aoc verify [day] [--answers answers.toml] [--record]
+    aoc bench [day] [part] [-n runs] [--save file] [--baseline file] [--threshold percent]
+    aoc list
+
+    both parts run when no part is given, input defaults to
+    inputs/<year>/<day>.txt, `-` reads from stdin and --example uses the
+    puzzle's published example; -v, -vv and -vvv log solver details to
+    stderr at info, debug and trace level (RUST_LOG takes precedence);
+    --set passes a setting to days that take one, e.g. day 5's
+    from=<category> and to=<category>, day 7's report=<file.csv|json>,
+    day 8's dot=<file> and starts=<node>,<node>..., or day 11's
+    factor=<n>, distances=bfs|dijkstra|analytic and formula=<file>; files
+    such as reports are written once, before solving, and from the first
+    example with --example; --step pauses at each solver checkpoint,
+    printing its state, and waits for c (continue, the default), s (skip
+    this checkpoint from now on) or q (quit)
+
+    verify checks every day's input and examples against the recorded
+    answers and exits non-zero if any differ; --record stores the answers
+    of checks that have none yet
+
+    bench prints min / median / max parse and solve times per day and part;
+    --save writes the medians to a baseline file and --baseline compares
+    against one, exiting non-zero when a part got slower than --threshold
+    percent (default 20)";
+
+const YEAR: u32 = 2023;
+
+fn registry() -> Registry {
+    let mut registry = Registry::new();
+
+    registry.register::<day_1::Day1>();
+    registry.register::<day_2::Day2>();
+    registry.register::<day_3::Day3>();
+    registry.register::<day_4::Day4>();
+    registry.register::<day_5::Day5>();
+    registry.register::<day_6::Day6>();
+    registry.register::<day_7::Day7>();
+    registry.register::<day_8::Day8>();
+    registry.register::<day_10::Day10>();
+    registry.register::<day_11::Day11>();
+    registry.register::<day_12::Day12>();
+
+    registry
+}
+
+enum Command {
+    Run { day: u32, config: Config },
+    Verify(VerifyConfig),
+    Bench(BenchConfig),
+    List,
+}
+
+impl Command {
+    fn build(args: &[String]) -> Result<Command, Box<dyn Error>> {
+        match args.get(1).map(String::as_str) {
+            Some("run") => {
+                let day = args
+                    .get(2)
+                    .ok_or("not enough arguments")?
+                    .parse()
+                    .map_err(|_| "day must be a number")?;
+                let config = Config::build(&args[3..])?;
+
+                Ok(Command::Run { day, config })
+            }
+            Some("verify") => Ok(Command::Verify(VerifyConfig::build(&args[2..])?)),
+            Some("bench") => Ok(Command::Bench(BenchConfig::build(&args[2..])?)),
+            Some("list") => Ok(Command::List),
+            Some(_) => Err("unknown command".into()),
+            None => Err("missing command".into()),
+        }
+    }
+}
+
+fn main() {
+    let args: Vec<String> = env::args().collect();
+
+    let command = Command::build(&args).unwrap_or_else(|err| {
+        eprintln!("Problem parsing arguments: {err}\n{USAGE}");
+        process::exit(2);
+    });
+
+    let registry = registry();
+
+    match command {
+        Command::Run { day, config } => {
+            init_tracing(config.verbosity);
+            if config.step {
+                step::install(TerminalStepper);
+            }
+            let format = config.format;
+            let runs = run(&registry, day, config).unwrap_or_else(|err| {
+                eprintln!("Error: {err}");
+                process::exit(1);
+            });
+
+            for run in &runs {
+                for answer in &run.answers {
+                    match format {
+                        OutputFormat::Text => {
+                            println!("Day {day} part {}: {}", answer.part, answer.value)
+                        }
+                        OutputFormat::Json => println!("{}", JsonAnswer::new(day, run, answer)),
+                    }
+                }
+            }
+        }
+        Command::Verify(config) => {
+            init_tracing(0);
+            let passed = verify(&registry, &config).unwrap_or_else(|err| {
+                eprintln!("Error: {err}");
+                process::exit(1);
+            });
+
+            if !passed {
+                process::exit(1);
+            }
+        }
+        Command::Bench(config) => {
+            init_tracing(0);
+            let passed = bench(&registry, &config).unwrap_or_else(|err| {
+                eprintln!("Error: {err}");
+                process::exit(1);
+            });
+
+            if !passed {
+                process::exit(1);
+            }
+        }
+        Command::List => {
+            for solution in registry.iter() {
+                println!("{} {}", solution.year(), solution.day());
+            }
+        }
+    }
+}
+
+/// Sends logs to stderr so stdout only carries answers. `RUST_LOG` overrides
+/// the level picked by `-v`.
+fn init_tracing(verbosity: u8) {
+    let level = match verbosity {
+        0 => "warn",
+        1 => "info",
+        2 => "debug",
+        _ => "trace",
+    };
+    let filter = EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new(level));
+
+    tracing_subscriber::fmt()
+        .with_env_filter(filter)
+        .with_writer(io::stderr)
+        .with_ansi(io::stderr().is_terminal())
+        .without_time()
+        .init();
+}
+
+/// Asks on stdin how to go on from each checkpoint; prompts go to stderr so
+/// stdout only carries answers.
+struct TerminalStepper;
+
+impl Stepper for TerminalStepper {
+    fn pause(&mut self, checkpoint: &str, state: &str) -> Action {
+        let mut stderr = io::stderr().lock();
+        let _ = writeln!(stderr, "[{checkpoint}] {state}");
+
+        loop {
+            let _ = write!(stderr, "(c)ontinue, (s)kip {checkpoint}, (q)uit> ");
+            let _ = stderr.flush();
+
+            let mut command = String::new();
+            match io::stdin().lock().read_line(&mut command) {
+                // Without a terminal to ask, run on.
+                Ok(0) | Err(_) => {
+                    let _ = writeln!(stderr);
+                    return Action::Continue;
+                }
+                Ok(_) => {}
+            }
+
+            match command.trim() {
+                "" | "c" | "continue" => return Action::Continue,
+                "s" | "skip" => return Action::Skip,
+                "q" | "quit" => return Action::Quit,
+                other => {
+                    let _ = writeln!(stderr, "unknown command `{other}`");
+                }
+            }
+        }
+    }
+}
+
+/// The answers computed from one input.
+struct Run {
+    input_hash: String,
+    answers: Vec<Answer>,
+}
+
+impl Run {
+    fn new(input: &str, answers: Vec<Answer>) -> Run {
+        let input_hash = input_hash(input);
+        for answer in &answers {
+            info!(part = %answer.part, elapsed = ?answer.elapsed, input_hash, "solved");
+        }
+
+        Run {
+            input_hash,
+            answers,
+        }
+    }
+}
+
+/// One line of `--format json` output.
+#[derive(Serialize)]
+struct JsonAnswer<'a> {
+    year: u32,
+    day: u32,
+    part: u32,
+    answer: &'a str,
+    elapsed_ns: u64,
+    input_hash: &'a str,
+}
+
+impl<'a> JsonAnswer<'a> {
+    fn new(day: u32, run: &'a Run, answer: &'a Answer) -> JsonAnswer<'a> {
+        JsonAnswer {
+            year: YEAR,
+            day,
+            part: answer.part.number(),
+            answer: &answer.value,
+            elapsed_ns: answer.elapsed.as_nanos().try_into().unwrap_or(u64::MAX),
+            input_hash: &run.input_hash,
+        }
+    }
+}
+
+impl fmt::Display for JsonAnswer<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let json = serde_json::to_string(self).map_err(|_| fmt::Error)?;
+        f.write_str(&json)
+    }
+}
+
+fn run(registry: &Registry, day: u32, config: Config) -> Result<Vec<Run>, Box<dyn Error>> {
+    let solution = registry
+        .get(YEAR, day)
+        .ok_or_else(|| format!("no solution registered for {YEAR} day {day}"))?;
+
+    let parts = match config.part {
+        Some(part) => vec![part],
+        None => Part::ALL.to_vec(),
+    };
+
+    if config.example {
+        // Parts may have different examples, so each is parsed on its own;
+        // exports come from the first one.
+        let mut runs = Vec::new();
+        for (i, part) in parts.into_iter().enumerate() {
+            let example = solution
+                .example(part)
+                .ok_or_else(|| format!("day {day} part {part} has no example"))?;
+            info!(%part, "reading embedded example");
+            let input = InputSource::Example(example).read()?;
+            runs.push(Run::new(
+                &input,
+                solution.run(&input, &[part], &config.params, i == 0)?,
+            ));
+        }
+        return Ok(runs);
+    }
+
+    let source = config
+        .input
+        .unwrap_or_else(|| InputSource::puzzle(YEAR, day));
+    info!(%source, "reading input");
+    let input = source.read()?;
+    let answers = solution.run(&input, &parts, &config.params, true)?;
+
+    Ok(vec![Run::new(&input, answers)])
+}
+
+/// Prints every check and a summary; returns whether nothing regressed.
+fn verify(registry: &Registry, config: &VerifyConfig) -> Result<bool, Box<dyn Error>> {
+    let mut answers = Answers::load(&config.answers)?;
+    let checks = verify::verify(registry, config, &mut answers);
+
+    let (mut passed, mut failed, mut missing) = (0, 0, 0);
+    for check in &checks {
+        println!("{check}");
+        match check.status {
+            Status::Pass => passed += 1,
+            Status::Fail { .. } => failed += 1,
+            Status::Missing => missing += 1,
+        }
+    }
+    println!("{passed} passed, {failed} failed, {missing} missing");
+
+    if config.record {
+        answers.save(&config.answers)?;
+    }
+
+    Ok(failed == 0)
+}
+
+/// Prints every benchmark and the total; returns whether nothing got slower
+/// than the baseline allows.
+fn bench(registry: &Registry, config: &BenchConfig) -> Result<bool, Box<dyn Error>> {
+    let baseline = config.baseline.as_deref().map(Baseline::load).transpose()?;
+    let benches = bench::bench(registry, config)?;
+
+    let mut regressions = 0;
+    for bench in &benches {
+        let previous = baseline.as_ref().and_then(|baseline| baseline.total(bench));
+        match previous {
+            Some(previous) => {
+                let change = bench::change(previous, bench.total());
+                let regressed = change > config.threshold;
+                if regressed {
+                    regressions += 1;
+                }
+                println!(
+                    "{bench}  {change:+.1}%{}",
+                    if regressed { " REGRESSED" } else { "" }
+                );
+            }
+            None => println!("{bench}"),
+        }
+    }
+
+    let parse: Duration = benches.iter().map(|bench| bench.parse.median).sum();
+    let solve: Duration = benches.iter().map(|bench| bench.solve.median).sum();
+    println!(
+        "total (medians)  parse {parse:.1?}  solve {solve:.1?}  all {:.1?}",
+        parse + solve
+    );
+
+    if baseline.is_some() {
+        println!("{regressions} regressed");
+    }
+
+    if let Some(path) = &config.save {
+        Baseline::new(&benches).save(path)?;
+    }
+
+    Ok(regressions == 0)
+}
@@ -0,0 +1,244 @@
+use crate::answers::{Answers, ANSWERS_FILE};
+use aoc_core::{ConfigError, InputSource, Params, Part, Registry, Runnable, Unsolved};
+use std::fmt;
+use std::path::PathBuf;
+
+/// Options for the `verify` command.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VerifyConfig {
+    pub day: Option<u32>,
+    pub answers: PathBuf,
+    /// Store the answers of checks that have no recorded answer yet.
+    pub record: bool,
+}
+
+impl VerifyConfig {
+    /// Builds a config from the arguments that follow `verify`:
+    /// `[day] [--answers <file>] [--record]`.
+    pub fn build(args: &[String]) -> Result<VerifyConfig, ConfigError> {
+        let mut config = VerifyConfig {
+            day: None,
+            answers: PathBuf::from(ANSWERS_FILE),
+            record: false,
+        };
+        let mut args = args.iter();
+
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--answers" => {
+                    let value = args.next().ok_or(ConfigError::MissingValue("--answers"))?;
+                    config.answers = PathBuf::from(value);
+                }
+                "--record" => config.record = true,
+                flag if flag.starts_with('-') => {
+                    return Err(ConfigError::UnknownFlag(flag.to_string()));
+                }
+                day if config.day.is_none() => match day.parse() {
+                    Ok(day) => config.day = Some(day),
+                    Err(_) => return Err(ConfigError::UnexpectedArgument(day.to_string())),
+                },
+                extra => return Err(ConfigError::UnexpectedArgument(extra.to_string())),
+            }
+        }
+
+        Ok(config)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Status {
+    Pass,
+    Fail {
+        expected: String,
+    },
+    /// No known answer, or no input to check against.
+    Missing,
+}
+
+/// The outcome of one part of one day against one input.
+#[derive(Debug)]
+pub struct Check {
+    pub year: u32,
+    pub day: u32,
+    pub input: String,
+    pub part: Part,
+    /// The computed answer, or why there is none.
+    pub actual: Result<String, String>,
+    pub status: Status,
+}
+
+impl fmt::Display for Check {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} day {} part {} ({}): ",
+            self.year, self.day, self.part, self.input
+        )?;
+
+        match (&self.status, &self.actual) {
+            (Status::Pass, Ok(actual)) => write!(f, "pass {actual}"),
+            (Status::Fail { expected }, Ok(actual)) => {
+                write!(f, "FAIL got {actual}, expected {expected}")
+            }
+            (Status::Fail { expected }, Err(err)) => {
+                write!(f, "FAIL {err}, expected {expected}")
+            }
+            (Status::Missing, Ok(actual)) => write!(f, "missing, got {actual}"),
+            (_, Err(err)) => write!(f, "missing, {err}"),
+        }
+    }
+}
+
+/// Runs every selected solution against its puzzle input and embedded
+/// examples and compares each part with the recorded answer.
+///
+/// With `config.record`, answers for missing checks are added to `answers`.
+pub fn verify(registry: &Registry, config: &VerifyConfig, answers: &mut Answers) -> Vec<Check> {
+    let mut checks = Vec::new();
+
+    let solutions = registry
+        .iter()
+        .filter(|solution| config.day.is_none_or(|day| solution.day() == day));
+
+    for solution in solutions {
+        let puzzle = InputSource::puzzle(solution.year(), solution.day());
+        checks.extend(check(solution, &puzzle, &Part::ALL, answers));
+
+        for part in Part::ALL {
+            if let Some(example) = solution.example(part) {
+                checks.extend(check(
+                    solution,
+                    &InputSource::Example(example),
+                    &[part],
+                    answers,
+                ));
+            }
+        }
+    }
+
+    if config.record {
+        for check in &checks {
+            match (&check.status, &check.actual) {
+                (Status::Missing, Ok(actual)) if *actual != Unsolved.to_string() => {
+                    answers.insert(
+                        check.year,
+                        check.day,
+                        &check.input,
+                        check.part,
+                        actual.clone(),
+                    );
+                }
+                _ => {}
+            }
+        }
+    }
+
+    checks
+}
+
+fn check(
+    solution: &dyn Runnable,
+    source: &InputSource,
+    parts: &[Part],
+    answers: &Answers,
+) -> Vec<Check> {
+    let (year, day) = (solution.year(), solution.day());
+    let input = source.to_string();
+
+    // Inputs are not always checked in, so an unreadable one is reported as
+    // missing rather than as a regression.
+    let text = match source.read() {
+        Ok(text) => text,
+        Err(err) => {
+            return parts
+                .iter()
+                .map(|&part| Check {
+                    year,
+                    day,
+                    input: input.clone(),
+                    part,
+                    actual: Err(err.to_string()),
+                    status: Status::Missing,
+                })
+                .collect()
+        }
+    };
+
+    let results: Vec<(Part, Result<String, String>)> =
+        match solution.run(&text, parts, &Params::new(), false) {
+            Ok(solved) => solved
+                .into_iter()
+                .map(|answer| (answer.part, Ok(answer.value)))
+                .collect(),
+            Err(err) => parts
+                .iter()
+                .map(|&part| (part, Err(format!("parse error: {err}"))))
+                .collect(),
+        };
+
+    results
+        .into_iter()
+        .map(|(part, actual)| {
+            let expected = answers.get(year, day, &input, part);
+            let status = match (expected, &actual) {
+                (None, _) => Status::Missing,
+                (Some(expected), Ok(actual)) if expected == actual => Status::Pass,
+                (Some(expected), _) => Status::Fail {
+                    expected: expected.to_string(),
+                },
+            };
+
+            Check {
+                year,
+                day,
+                input: input.clone(),
+                part,
+                actual,
+                status,
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn build(args: &[&str]) -> Result<VerifyConfig, ConfigError> {
+        let args: Vec<String> = args.iter().map(|arg| arg.to_string()).collect();
+        VerifyConfig::build(&args)
+    }
+
+    #[test]
+    fn defaults_and_flags() {
+        let config = build(&[]).unwrap();
+        assert_eq!(config.day, None);
+        assert_eq!(config.answers, PathBuf::from(ANSWERS_FILE));
+        assert!(!config.record);
+
+        let config = build(&["7", "--answers", "other.toml", "--record"]).unwrap();
+        assert_eq!(config.day, Some(7));
+        assert_eq!(config.answers, PathBuf::from("other.toml"));
+        assert!(config.record);
+    }
+
+    #[test]
+    fn rejects_bad_arguments() {
+        assert!(matches!(
+            build(&["--answers"]),
+            Err(ConfigError::MissingValue("--answers"))
+        ));
+        assert!(matches!(
+            build(&["--recrod"]),
+            Err(ConfigError::UnknownFlag(flag)) if flag == "--recrod"
+        ));
+        assert!(matches!(
+            build(&["seven"]),
+            Err(ConfigError::UnexpectedArgument(arg)) if arg == "seven"
+        ));
+        assert!(matches!(
+            build(&["7", "8"]),
+            Err(ConfigError::UnexpectedArgument(arg)) if arg == "8"
+        ));
+    }
+}
@@ -0,0 +1,160 @@
+use aoc_core::{step, Part, Solution};
+use std::collections::HashMap;
+use std::error::Error;
+
+const EXAMPLE_1: &str = "\
+1abc2
+pqr3stu8vwx
+a1b2c3d4e5f
+treb7uchet
+";
+
+const EXAMPLE_2: &str = "\
+two1nine
+eightwothree
+abcone2threexyz
+xtwone3four
+4nineeightseven2
+zoneight234
+7pqrstsixteen
+";
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    const YEAR: u32 = 2023;
+    const DAY: u32 = 1;
+
+    type Model = Vec<String>;
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn example(part: Part) -> Option<&'static str> {
+        match part {
+            Part::One => Some(EXAMPLE_1),
+            Part::Two => Some(EXAMPLE_2),
+        }
+    }
+
+    fn parse(input: &str) -> Result<Vec<String>, Box<dyn Error>> {
+        Ok(input.lines().map(str::to_string).collect())
+    }
+
+    fn part1(lines: &Vec<String>) -> i32 {
+        let mut sum = 0;
+
+        for line in lines {
+            let mut first_digit: Option<char> = None;
+            let mut last_digit: Option<char> = None;
+
+            for c in line.chars() {
+                if c.is_numeric() {
+                    if first_digit.is_none() {
+                        first_digit = Some(c);
+                    }
+                    last_digit = Some(c);
+                }
+            }
+
+            let calibration_value = first_digit.map(|c| c.to_string()).unwrap_or_default()
+                + &last_digit.map(|c| c.to_string()).unwrap_or_default();
+            step::checkpoint("calibration", || format!("{line} -> {calibration_value}"));
+            sum += calibration_value.parse().unwrap_or(0);
+        }
+
+        sum
+    }
+
+    fn part2(lines: &Vec<String>) -> i32 {
+        let digit_mapping = get_digits_hash_map();
+        let mut sum = 0;
+
+        for line in lines {
+            let line_as_chars: Vec<char> = line.chars().collect();
+
+            let mut first_digit: Option<i32> = None;
+            let mut last_digit: Option<i32> = None;
+
+            let mut set_first_and_last_digit = |key: &str| {
+                if let Some(digit) = digit_mapping.get(key) {
+                    if first_digit.is_none() {
+                        first_digit = Some(*digit);
+                    }
+                    last_digit = Some(*digit);
+                }
+            };
+
+            for i in 0..line_as_chars.len() {
+                let key = line_as_chars[i].to_string();
+                set_first_and_last_digit(&key);
+
+                for j in i..line_as_chars.len() {
+                    let key = line_as_chars[i..j + 1].iter().collect::<String>();
+                    set_first_and_last_digit(&key);
+                }
+            }
+
+            let first_digit_unwrapped = first_digit.unwrap_or_default().to_string();
+            let last_digit_unwrapped = last_digit.unwrap_or_default().to_string();
+            let calibration_value = first_digit_unwrapped + &last_digit_unwrapped;
+            step::checkpoint("calibration", || format!("{line} -> {calibration_value}"));
+            sum += calibration_value.parse().unwrap_or(0);
+        }
+
+        sum
+    }
+}
+
+fn get_digits_hash_map() -> HashMap<String, i32> {
+    let mut numbers = HashMap::new();
+
+    numbers.insert("zero".to_string(), 0);
+    numbers.insert("one".to_string(), 1);
+    numbers.insert("two".to_string(), 2);
+    numbers.insert("three".to_string(), 3);
+    numbers.insert("four".to_string(), 4);
+    numbers.insert("five".to_string(), 5);
+    numbers.insert("six".to_string(), 6);
+    numbers.insert("seven".to_string(), 7);
+    numbers.insert("eight".to_string(), 8);
+    numbers.insert("nine".to_string(), 9);
+
+    for i in 0..10 {
+        let key = format!("{}", i);
+        numbers.insert(key, i);
+    }
+
+    numbers
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn solve(part: fn(&Vec<String>) -> i32, input: &str) -> i32 {
+        part(&Day1::parse(input).unwrap())
+    }
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(solve(Day1::part1, EXAMPLE_1), 142);
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(solve(Day1::part2, EXAMPLE_2), 281);
+    }
+
+    #[test]
+    fn single_digit_is_first_and_last() {
+        assert_eq!(solve(Day1::part1, "treb7uchet"), 77);
+        assert_eq!(solve(Day1::part2, "seven"), 77);
+    }
+
+    #[test]
+    fn overlapping_spelled_digits() {
+        assert_eq!(solve(Day1::part2, "eightwo"), 82);
+        assert_eq!(solve(Day1::part2, "oneight"), 18);
+        assert_eq!(solve(Day1::part2, "twone3sevenine"), 29);
+    }
+}
@@ -0,0 +1,269 @@
+use aoc_core::grid::NEIGHBORS_4;
+use aoc_core::{search, step, Grid, ParseError, Part, Pos, Solution};
+use std::error::Error;
+use tracing::{debug, trace};
+
+const T: &char = &'T';
+const R: &char = &'R';
+const B: &char = &'B';
+const L: &char = &'L';
+
+const PV: &char = &'|';
+const PJ: &char = &'J';
+const PF: &char = &'F';
+const PL: &char = &'L';
+const P7: &char = &'7';
+const PH: &char = &'-';
+const S: &char = &'S';
+
+fn get_matching_pipes<'a>(oritentation: &'a char, pipe: &'a char) -> Vec<&'a char> {
+    match (oritentation, pipe) {
+        (T, S) => vec![PV, P7, PF],
+        (R, S) => vec![PH, P7, PJ],
+        (B, S) => vec![PV, PJ, PL],
+        (L, S) => vec![PH, PL, PF],
+        (T, PV) => vec![PV, PF, P7],
+        (B, PV) => vec![PV, PL, PJ],
+        (R, PH) => vec![PH, P7, PJ],
+        (L, PH) => vec![PH, PL, PF],
+        (T, PL) => vec![PV, PF, P7],
+        (R, PL) => vec![PH, P7, PJ],
+        (T, PJ) => vec![PV, PF, P7],
+        (L, PJ) => vec![PH, PL, PF],
+        (B, P7) => vec![PV, PJ, PL],
+        (L, P7) => vec![PH, PL, PF],
+        (R, PF) => vec![PH, P7, PJ],
+        (B, PF) => vec![PV, PJ, PL],
+        _ => vec![],
+    }
+}
+
+fn get_oposite_orientation(oritentation: &char) -> &char {
+    let oposite_orientation = match oritentation {
+        T => B,
+        R => L,
+        B => T,
+        L => R,
+        _ => panic!("Invalid orientation"),
+    };
+
+    oposite_orientation
+}
+
+const EXAMPLE_1: &str = "\
+..F7.
+.FJ|.
+SJ.L7
+|F--J
+LJ...
+";
+
+const EXAMPLE_2: &str = "\
+...........
+.S-------7.
+.|F-----7|.
+.||.....||.
+.||.....||.
+.|L-7.F-J|.
+.|..|.|..|.
+.L--J.L--J.
+...........
+";
+
+pub struct Day10;
+
+pub struct Field {
+    pub grid: Grid<char>,
+    pub start_pos: Pos,
+}
+
+impl Solution for Day10 {
+    const YEAR: u32 = 2023;
+    const DAY: u32 = 10;
+
+    type Model = Field;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn example(part: Part) -> Option<&'static str> {
+        match part {
+            Part::One => Some(EXAMPLE_1),
+            Part::Two => Some(EXAMPLE_2),
+        }
+    }
+
+    fn parse(input: &str) -> Result<Field, Box<dyn Error>> {
+        let grid = Grid::parse(input, |c| "|-LJ7F.S".contains(c).then_some(c))?;
+        let start_pos = grid
+            .find(S)
+            .ok_or_else(|| ParseError::new(1, 1, "", "missing start tile `S`"))?;
+
+        debug!(
+            rows = grid.rows(),
+            cols = grid.cols(),
+            start = ?start_pos,
+            "parsed field"
+        );
+
+        Ok(Field { grid, start_pos })
+    }
+
+    fn part1(field: &Field) -> i64 {
+        let pipe_path = get_pipe_path(field.start_pos, &field.grid);
+
+        let middle_distance = pipe_path.len() / 2;
+        debug!(loop_length = pipe_path.len(), "found loop");
+        step::checkpoint("loop", || {
+            format!("{} tiles: {pipe_path:?}", pipe_path.len())
+        });
+
+        middle_distance as i64
+    }
+
+    fn part2(field: &Field) -> i64 {
+        let grid = &field.grid;
+        let pipe_path = get_pipe_path(field.start_pos, grid);
+
+        let mut on_path = Grid::new(grid.rows(), grid.cols(), false);
+        for &pos in &pipe_path {
+            on_path[pos] = true;
+        }
+
+        on_path
+            .positions(|on_path| !on_path)
+            .filter(|&pos| winding_number(pos, &pipe_path) != 0)
+            .count() as i64
+    }
+}
+
+fn get_pipe_path(start_pos: Pos, grid: &Grid<char>) -> Vec<Pos> {
+    // Only the two pipes of the loop connect to each tile on it, so a
+    // depth-first walk from the start visits the loop in order.
+    let search = search::dfs(start_pos, |&pos| get_connected_pipes(pos, grid));
+    let path = search.order().to_vec();
+    trace!(?path, "pipe path");
+
+    path
+}
+
+fn get_connected_pipes(pos: Pos, grid: &Grid<char>) -> Vec<Pos> {
+    let pipe = &grid[pos];
+
+    // NEIGHBORS_4 runs top, right, bottom, left
+    NEIGHBORS_4
+        .iter()
+        .zip([T, R, B, L])
+        .filter_map(|(&delta, o)| Some((grid.offset(pos, delta)?, o)))
+        .filter(|&(ajdacent, o)| {
+            let ajdacent_pipe = &grid[ajdacent];
+            let ajdacent_oriented_pipes =
+                get_matching_pipes(get_oposite_orientation(o), ajdacent_pipe);
+            let matching_pipes = get_matching_pipes(o, pipe);
+            ajdacent_oriented_pipes
+                .iter()
+                .any(|ajdacent_oriented_pipe| matching_pipes.contains(ajdacent_oriented_pipe))
+        })
+        .map(|(ajdacent, _)| ajdacent)
+        .collect()
+}
+
+fn winding_number(point: Pos, polygon: &[Pos]) -> i32 {
+    let mut wn = 0; // the winding number
+    let n = polygon.len(); // the number of vertices
+    for i in 0..n {
+        // loop through all edges of the polygon
+        let j = (i + 1) % n; // the next vertex index
+        if polygon[i].1 <= point.1 {
+            // start y <= point y
+            if polygon[j].1 > point.1 {
+                // an upward crossing
+                if is_left(polygon[i], polygon[j], point) > 0.0 {
+                    // point left of edge
+                    wn += 1; // have a valid up intersect
+                }
+            }
+        } else {
+            // start y > point y (no test needed)
+            if polygon[j].1 <= point.1 {
+                // a downward crossing
+                if is_left(polygon[i], polygon[j], point) < 0.0 {
+                    // point right of edge
+                    wn -= 1; // have a valid down intersect
+                }
+            }
+        }
+    }
+    wn
+}
+
+// Define a function to check if a point is to the left of a line segment
+fn is_left(p1: Pos, p2: Pos, p3: Pos) -> f64 {
+    let p1 = (p1.0 as f64, p1.1 as f64);
+    let p2 = (p2.0 as f64, p2.1 as f64);
+    let p3 = (p3.0 as f64, p3.1 as f64);
+
+    (p2.0 - p1.0) * (p3.1 - p1.1) - (p3.0 - p1.0) * (p2.1 - p1.1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part1_example() {
+        let field = Day10::parse(EXAMPLE_1).unwrap();
+        assert_eq!(Day10::part1(&field), 8);
+    }
+
+    #[test]
+    fn part1_simple_loop_with_noise() {
+        let field = Day10::parse("-L|F7\n7S-7|\nL|7||\n-L-J|\nL|-JF").unwrap();
+        assert_eq!(field.start_pos, (1, 1));
+        assert_eq!(Day10::part1(&field), 4);
+    }
+
+    #[test]
+    fn part2_example() {
+        let field = Day10::parse(EXAMPLE_2).unwrap();
+        assert_eq!(Day10::part2(&field), 4);
+    }
+
+    #[test]
+    fn part2_squeezing_between_pipes_does_not_escape() {
+        let input = "\
+..........
+.S------7.
+.|F----7|.
+.||....||.
+.||....||.
+.|L-7F-J|.
+.|..||..|.
+.L--JL--J.
+..........";
+        let field = Day10::parse(input).unwrap();
+        assert_eq!(Day10::part2(&field), 4);
+    }
+
+    #[test]
+    fn part2_larger_example() {
+        let input = "\
+.F----7F7F7F7F-7....
+.|F--7||||||||FJ....
+.||.FJ||||||||L7....
+FJL7L7LJLJ||LJ.L-7..
+L--J.L7...LJS7F-7L7.
+....F-J..F7FJ|L7L7L7
+....L7.F7||L7|.L7L7|
+.....|FJLJ|FJ|F7|.LJ
+....FJL-7.||.||||...
+....L---J.LJ.LJLJ...";
+        let field = Day10::parse(input).unwrap();
+        assert_eq!(Day10::part2(&field), 8);
+    }
+
+    #[test]
+    fn missing_start_is_an_error() {
+        let err = Day10::parse("F7\nLJ").err().unwrap();
+        assert_eq!(err.to_string(), "line 1, column 1: missing start tile `S`");
+    }
+}
@@ -0,0 +1,491 @@
+use aoc_core::search::{self, Search};
+use aoc_core::{step, Grid, ParamError, Params, Part, Pos, Solution};
+use rayon::prelude::*;
+use std::collections::HashSet;
+use std::error::Error;
+use std::fmt;
+use std::fs;
+use std::str::FromStr;
+use tracing::info;
+
+const EXAMPLE: &str = "\
+...#......
+.......#..
+#.........
+..........
+......#...
+.#........
+.........#
+..........
+.......#..
+#...#.....
+";
+
+const PART2_EXPANSION_FACTOR: i64 = 1_000_000;
+
+pub struct Day11;
+
+/// How the distances between galaxies are computed, picked with
+/// `--set distances=<mode>`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DistanceMode {
+    /// A breadth-first search per galaxy, adding the expansion to the steps
+    /// of its search tree.
+    Bfs,
+    /// Dijkstra per galaxy, with steps into an empty row or column weighted
+    /// by the expansion factor.
+    Dijkstra,
+    /// Manhattan distances between expanded coordinates, summed over all
+    /// pairs from prefix sums without touching the grid.
+    #[default]
+    Analytic,
+}
+
+impl FromStr for DistanceMode {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<DistanceMode, ()> {
+        match s {
+            "bfs" => Ok(DistanceMode::Bfs),
+            "dijkstra" => Ok(DistanceMode::Dijkstra),
+            "analytic" => Ok(DistanceMode::Analytic),
+            _ => Err(()),
+        }
+    }
+}
+
+pub struct Universe {
+    pub grid: Grid<char>,
+    pub galaxy_indexes: Vec<Pos>,
+    pub expanded_rows: HashSet<usize>,
+    pub expanded_cols: HashSet<usize>,
+    pub mode: DistanceMode,
+    /// How many rows or columns each empty one becomes in part 2, set with
+    /// `--set factor=<n>`. Part 1 always uses 2.
+    pub expansion_factor: i64,
+}
+
+/// The sum of shortest paths as a function of the expansion factor: every
+/// shortest path crosses the same empty rows and columns whatever the
+/// factor, so it is linear. Written with `--set formula=<file>`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ExpansionFormula {
+    /// The sum before expansion, with a factor of 1.
+    pub unexpanded: i64,
+    /// Empty rows and columns crossed, summed over every pair.
+    pub crossings: i64,
+}
+
+impl ExpansionFormula {
+    pub fn new(universe: &Universe) -> ExpansionFormula {
+        let sum =
+            |factor| sum_of_manhattan_distances(universe, factor).expect("distances overflow");
+        let unexpanded = sum(1);
+
+        ExpansionFormula {
+            unexpanded,
+            crossings: sum(2) - unexpanded,
+        }
+    }
+
+    /// The sum with `expansion_factor`, or `None` if it overflows.
+    pub fn at(&self, expansion_factor: i64) -> Option<i64> {
+        self.crossings
+            .checked_mul(expansion_factor - 1)?
+            .checked_add(self.unexpanded)
+    }
+}
+
+impl fmt::Display for ExpansionFormula {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} + {} * (factor - 1)", self.unexpanded, self.crossings)
+    }
+}
+
+impl Solution for Day11 {
+    const YEAR: u32 = 2023;
+    const DAY: u32 = 11;
+
+    type Model = Universe;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn example(_: Part) -> Option<&'static str> {
+        Some(EXAMPLE)
+    }
+
+    fn parse(input: &str) -> Result<Universe, Box<dyn Error>> {
+        let grid = Grid::parse(input, |c| matches!(c, '.' | '#').then_some(c))?;
+        let galaxy_indexes: Vec<Pos> = grid.positions(|c| *c == '#').collect();
+
+        let expanded_rows: HashSet<_> = (0..grid.rows())
+            .filter(|&i| grid.row(i).iter().all(|c| *c == '.'))
+            .collect();
+
+        let expanded_cols: HashSet<_> = (0..grid.cols())
+            .filter(|&j| grid.column(j).all(|c| *c == '.'))
+            .collect();
+
+        Ok(Universe {
+            grid,
+            galaxy_indexes,
+            expanded_rows,
+            expanded_cols,
+            mode: DistanceMode::default(),
+            expansion_factor: PART2_EXPANSION_FACTOR,
+        })
+    }
+
+    fn parse_with(input: &str, params: &Params) -> Result<Universe, Box<dyn Error>> {
+        params.check(&["distances", "factor", "formula"])?;
+
+        let mut universe = Self::parse(input)?;
+        if let Some(mode) = params.get("distances")? {
+            universe.mode = mode;
+        }
+        if let Some(factor) = params.get::<i64>("factor")? {
+            if factor < 1 {
+                return Err(ParamError::InvalidValue {
+                    key: "factor".to_string(),
+                    value: factor.to_string(),
+                }
+                .into());
+            }
+            if !fits(&universe, factor.max(2)) {
+                return Err(format!(
+                    "expansion factor {factor} is too large: the distances overflow"
+                )
+                .into());
+            }
+            universe.expansion_factor = factor;
+        }
+
+        Ok(universe)
+    }
+
+    /// Writes the sum of shortest paths as a function of the expansion
+    /// factor to the `formula` file, if given.
+    fn export(universe: &Universe, params: &Params) -> Result<(), Box<dyn Error>> {
+        if let Some(path) = params.get::<String>("formula")? {
+            let formula = ExpansionFormula::new(universe);
+            fs::write(&path, format!("{formula}\n"))?;
+            info!(path, %formula, "wrote the expansion formula");
+        }
+        Ok(())
+    }
+
+    fn part1(universe: &Universe) -> i64 {
+        sum_of_shortest_paths(universe, 2)
+    }
+
+    fn part2(universe: &Universe) -> i64 {
+        let formula = ExpansionFormula::new(universe);
+        info!(%formula, "sum of shortest paths by expansion factor");
+        step::checkpoint("formula", || formula.to_string());
+        sum_of_shortest_paths(universe, universe.expansion_factor)
+    }
+}
+
+/// Sum of the shortest paths between every pair of galaxies, with each empty
+/// row and column counting as `expansion_factor` of them.
+fn sum_of_shortest_paths(universe: &Universe, expansion_factor: i64) -> i64 {
+    let galaxy_indexes = &universe.galaxy_indexes;
+
+    if universe.mode == DistanceMode::Analytic {
+        return sum_of_manhattan_distances(universe, expansion_factor)
+            .expect("expansion factor checked by parse_with");
+    }
+
+    galaxy_indexes
+        .par_iter()
+        .enumerate()
+        .map(|(i, &idx)| {
+            let targets = &galaxy_indexes[i..];
+            match universe.mode {
+                DistanceMode::Dijkstra => {
+                    let search = get_weighted_distances(idx, universe, expansion_factor);
+                    targets
+                        .iter()
+                        .map(|pos| search.distance(pos).unwrap())
+                        .sum::<i64>()
+                }
+                _ => {
+                    let (distances, _) = get_distances(
+                        idx,
+                        &universe.grid,
+                        &universe.expanded_rows,
+                        &universe.expanded_cols,
+                        expansion_factor,
+                    );
+                    targets.iter().map(|&pos| distances[pos]).sum()
+                }
+            }
+        })
+        .sum()
+}
+
+/// Whether every distance and sum with `expansion_factor` fits in an
+/// `i64`: the searches never go further than across the whole expanded
+/// grid and one more step.
+fn fits(universe: &Universe, expansion_factor: i64) -> bool {
+    let extent = |len: usize, empty: usize| {
+        (empty as i64)
+            .checked_mul(expansion_factor - 1)?
+            .checked_add(len as i64)
+    };
+    let grid = &universe.grid;
+    let farthest = extent(grid.rows(), universe.expanded_rows.len())
+        .zip(extent(grid.cols(), universe.expanded_cols.len()))
+        .and_then(|(rows, cols)| rows.checked_add(cols)?.checked_add(expansion_factor));
+
+    farthest.is_some() && sum_of_manhattan_distances(universe, expansion_factor).is_some()
+}
+
+/// Galaxies never share a row or column with an empty one, so a shortest
+/// path is just the Manhattan distance between expanded coordinates, and
+/// rows and columns can be summed separately. `None` if the sum or an
+/// expanded coordinate does not fit in an `i64`.
+fn sum_of_manhattan_distances(universe: &Universe, expansion_factor: i64) -> Option<i64> {
+    let expand = |empty: &HashSet<usize>, coords: Vec<usize>| {
+        let mut empty: Vec<usize> = empty.iter().copied().collect();
+        empty.sort_unstable();
+
+        coords
+            .into_iter()
+            .map(|coord| {
+                let empty_before = empty.partition_point(|&e| e < coord) as i64;
+                empty_before
+                    .checked_mul(expansion_factor - 1)?
+                    .checked_add(coord as i64)
+            })
+            .collect::<Option<Vec<i64>>>()
+    };
+
+    let galaxies = &universe.galaxy_indexes;
+    let rows = expand(
+        &universe.expanded_rows,
+        galaxies.iter().map(|pos| pos.0).collect(),
+    )?;
+    let cols = expand(
+        &universe.expanded_cols,
+        galaxies.iter().map(|pos| pos.1).collect(),
+    )?;
+
+    i64::try_from(sum_of_pairwise_differences(rows) + sum_of_pairwise_differences(cols)).ok()
+}
+
+/// Sum of `|a - b|` over every pair: once sorted, each coordinate is the
+/// larger one in a pair with every coordinate before it. Summed in `i128`,
+/// where the prefix sums cannot overflow.
+fn sum_of_pairwise_differences(mut coords: Vec<i64>) -> i128 {
+    coords.sort_unstable();
+
+    let mut before = 0;
+    let mut total = 0;
+    for (k, &coord) in coords.iter().enumerate() {
+        total += coord as i128 * k as i128 - before;
+        before += coord as i128;
+    }
+
+    total
+}
+
+/// Shortest distances from `source`, where moving into an empty row costs
+/// `expansion_factor` when moving vertically and into an empty column when
+/// moving horizontally.
+fn get_weighted_distances(
+    source: Pos,
+    universe: &Universe,
+    expansion_factor: i64,
+) -> Search<Pos, i64> {
+    let weight = |from: Pos, to: Pos| {
+        let expanded = if from.0 != to.0 {
+            universe.expanded_rows.contains(&to.0)
+        } else {
+            universe.expanded_cols.contains(&to.1)
+        };
+        if expanded {
+            expansion_factor
+        } else {
+            1
+        }
+    };
+
+    search::dijkstra(source, |&pos| {
+        universe
+            .grid
+            .neighbors4(pos)
+            .map(move |next| (next, weight(pos, next)))
+    })
+}
+
+/// Distances from `source` to every cell, along the breadth-first search
+/// tree. The grid has no walls, so every tree path is a shortest path that
+/// never steps back across a row or column, and it crosses exactly the empty
+/// rows and columns between its ends. Charging `expansion_factor` for the
+/// step across one, and not for steps along it, gives the expanded
+/// Manhattan distance.
+fn get_distances(
+    source: Pos,
+    grid: &Grid<char>,
+    expanded_rows: &HashSet<usize>,
+    expanded_cols: &HashSet<usize>,
+    expansion_factor: i64,
+) -> (Grid<i64>, Search<Pos, usize>) {
+    let search = search::bfs(source, |&pos| grid.neighbors4(pos));
+    let mut distances = Grid::new(grid.rows(), grid.cols(), i64::MAX);
+
+    distances[source] = 0;
+
+    for &pos in &search.order()[1..] {
+        let parent = search.predecessor(&pos).unwrap();
+        let expanded = if parent.0 != pos.0 {
+            expanded_rows.contains(&pos.0)
+        } else {
+            expanded_cols.contains(&pos.1)
+        };
+        distances[pos] = distances[*parent] + if expanded { expansion_factor } else { 1 };
+    }
+
+    (distances, search)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part2_example() {
+        let universe = Day11::parse(EXAMPLE).unwrap();
+        assert_eq!(Day11::part2(&universe), 82000210);
+    }
+
+    #[test]
+    fn expansion_factors() {
+        let universe = Day11::parse(EXAMPLE).unwrap();
+        assert_eq!(sum_of_shortest_paths(&universe, 2), 374);
+        assert_eq!(sum_of_shortest_paths(&universe, 10), 1030);
+        assert_eq!(sum_of_shortest_paths(&universe, 100), 8410);
+    }
+
+    #[test]
+    fn part1_example() {
+        let universe = Day11::parse(EXAMPLE).unwrap();
+        assert_eq!(Day11::part1(&universe), 374);
+    }
+
+    #[test]
+    fn answer_is_linear_in_the_factor() {
+        let universe = Day11::parse(EXAMPLE).unwrap();
+        let formula = ExpansionFormula::new(&universe);
+        assert_eq!(formula.to_string(), "292 + 82 * (factor - 1)");
+        assert_eq!(formula.at(1_000_000), Some(82000210));
+        assert_eq!(formula.at(10), Some(sum_of_shortest_paths(&universe, 10)));
+        assert_eq!(formula.at(i64::MAX), None);
+    }
+
+    #[test]
+    fn exported_formula_gives_both_parts() {
+        let path = std::env::temp_dir().join(format!("day_11_{}.txt", std::process::id()));
+        let mut params: Params = format!("formula={}", path.display()).parse().unwrap();
+        params.insert("factor", "10");
+        let universe = Day11::parse_with(EXAMPLE, &params).unwrap();
+        assert!(!path.exists());
+        Day11::export(&universe, &params).unwrap();
+        let written = fs::read_to_string(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+
+        // Evaluate the formula as printed, `a + b * (factor - 1)`.
+        let (unexpanded, rest) = written.trim_end().split_once(" + ").unwrap();
+        let crossings = rest.strip_suffix(" * (factor - 1)").unwrap();
+        let (unexpanded, crossings): (i64, i64) =
+            (unexpanded.parse().unwrap(), crossings.parse().unwrap());
+        let at = |factor: i64| unexpanded + crossings * (factor - 1);
+        assert_eq!(at(2), Day11::part1(&universe));
+        assert_eq!(at(10), Day11::part2(&universe));
+    }
+
+    #[test]
+    fn factor_is_a_parameter() {
+        let params: Params = "factor=10".parse().unwrap();
+        let universe = Day11::parse_with(EXAMPLE, &params).unwrap();
+        assert_eq!(Day11::part1(&universe), 374);
+        assert_eq!(Day11::part2(&universe), 1030);
+
+        let params: Params = "factor=0".parse().unwrap();
+        assert!(Day11::parse_with(EXAMPLE, &params).is_err());
+    }
+
+    #[test]
+    fn overflowing_factor_is_an_error() {
+        let params: Params = format!("factor={}", i64::MAX).parse().unwrap();
+        let err = Day11::parse_with(EXAMPLE, &params).err().unwrap();
+        assert_eq!(
+            err.to_string(),
+            "expansion factor 9223372036854775807 is too large: the distances overflow"
+        );
+
+        // The largest factor with every galaxy pair summed still in range.
+        let formula = ExpansionFormula::new(&Day11::parse(EXAMPLE).unwrap());
+        let largest = (i64::MAX - formula.unexpanded) / formula.crossings + 1;
+        let params: Params = format!("factor={largest}").parse().unwrap();
+        let universe = Day11::parse_with(EXAMPLE, &params).unwrap();
+        assert_eq!(Some(Day11::part2(&universe)), formula.at(largest));
+        let params: Params = format!("factor={}", largest + 1).parse().unwrap();
+        assert!(Day11::parse_with(EXAMPLE, &params).is_err());
+    }
+
+    #[test]
+    fn distance_modes_agree() {
+        let mut universe = Day11::parse(EXAMPLE).unwrap();
+        for mode in [
+            DistanceMode::Bfs,
+            DistanceMode::Dijkstra,
+            DistanceMode::Analytic,
+        ] {
+            universe.mode = mode;
+            assert_eq!(sum_of_shortest_paths(&universe, 2), 374, "{mode:?}");
+            assert_eq!(sum_of_shortest_paths(&universe, 100), 8410, "{mode:?}");
+        }
+    }
+
+    #[test]
+    fn pairwise_differences() {
+        assert_eq!(sum_of_pairwise_differences(vec![]), 0);
+        assert_eq!(
+            sum_of_pairwise_differences(vec![5, 1, 3, 3]),
+            2 + 2 + 4 + 2 + 2
+        );
+    }
+
+    #[test]
+    fn mode_is_a_parameter() {
+        let params: Params = "distances=dijkstra".parse().unwrap();
+        let universe = Day11::parse_with(EXAMPLE, &params).unwrap();
+        assert_eq!(universe.mode, DistanceMode::Dijkstra);
+
+        let params: Params = "distances=fast".parse().unwrap();
+        let err = Day11::parse_with(EXAMPLE, &params).err().unwrap();
+        assert_eq!(
+            err.to_string(),
+            "invalid value `fast` for parameter `distances`"
+        );
+
+        let params: Params = "expansion=2".parse().unwrap();
+        assert!(Day11::parse_with(EXAMPLE, &params).is_err());
+    }
+
+    #[test]
+    fn finds_empty_rows_and_columns() {
+        let universe = Day11::parse(EXAMPLE).unwrap();
+        assert_eq!(universe.galaxy_indexes.len(), 9);
+        assert_eq!(universe.expanded_rows, HashSet::from([3, 7]));
+        assert_eq!(universe.expanded_cols, HashSet::from([2, 5, 8]));
+    }
+
+    #[test]
+    fn single_galaxy_has_no_pairs() {
+        let universe = Day11::parse("...\n.#.\n...").unwrap();
+        assert_eq!(sum_of_shortest_paths(&universe, 2), 0);
+    }
+}
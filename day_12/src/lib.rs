@@ -0,0 +1,88 @@
+use aoc_core::{lines, step, Part, Solution, Unsolved};
+use std::error::Error;
+use tracing::trace;
+
+const EXAMPLE: &str = "\
+???.### 1,1,3
+.??..??...?##. 1,1,3
+?#?#?#?#?#?#?#? 1,3,1,6
+????.#...#... 4,1,1
+????.######..#####. 1,6,5
+?###???????? 3,2,1
+";
+
+pub struct Day12;
+
+pub struct Record {
+    pub records: Vec<char>,
+    pub damaged_records: Vec<i32>,
+}
+
+impl Solution for Day12 {
+    const YEAR: u32 = 2023;
+    const DAY: u32 = 12;
+
+    type Model = Vec<Record>;
+    type Answer1 = Unsolved;
+    type Answer2 = Unsolved;
+
+    fn example(_: Part) -> Option<&'static str> {
+        Some(EXAMPLE)
+    }
+
+    fn parse(input: &str) -> Result<Vec<Record>, Box<dyn Error>> {
+        let mut rows = Vec::new();
+
+        for line in lines(input) {
+            let (records, damaged_records) = line.split_once(" ")?;
+            if let Some(record) = records.matches(|c| !".#?".contains(c)).next() {
+                return Err(line.error(record, "invalid spring").into());
+            }
+            let records = records.chars().collect::<Vec<char>>();
+            let damaged_records = damaged_records
+                .split(',')
+                .map(|n| line.parse(n))
+                .collect::<Result<Vec<i32>, _>>()?;
+
+            trace!(?records, ?damaged_records, "parsed row");
+            step::checkpoint("row", || format!("{} {damaged_records:?}", line.text));
+
+            rows.push(Record {
+                records,
+                damaged_records,
+            });
+        }
+
+        Ok(rows)
+    }
+
+    fn part1(_: &Vec<Record>) -> Unsolved {
+        Unsolved
+    }
+
+    fn part2(_: &Vec<Record>) -> Unsolved {
+        Unsolved
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_example() {
+        let rows = Day12::parse(EXAMPLE).unwrap();
+        assert_eq!(rows.len(), 6);
+        assert_eq!(
+            rows[1].records,
+            ".??..??...?##.".chars().collect::<Vec<_>>()
+        );
+        assert_eq!(rows[2].damaged_records, [1, 3, 1, 6]);
+    }
+
+    #[test]
+    fn invalid_spring_is_an_error() {
+        let err = Day12::parse("??x# 1,1").err().unwrap();
+        assert_eq!(err.to_string(), "line 1, column 3: invalid spring: `x`");
+    }
+}
@@ -0,0 +1,174 @@
+use aoc_core::{lines, step, Part, Solution};
+use std::cmp;
+use std::error::Error;
+use tracing::debug;
+
+const EXAMPLE: &str = "\
+Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green
+";
+
+pub struct Day2;
+
+pub struct Game {
+    pub id: i32,
+    pub sets: Vec<CubeSet>,
+}
+
+#[derive(Default)]
+pub struct CubeSet {
+    pub red: i32,
+    pub green: i32,
+    pub blue: i32,
+}
+
+impl Solution for Day2 {
+    const YEAR: u32 = 2023;
+    const DAY: u32 = 2;
+
+    type Model = Vec<Game>;
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn example(_: Part) -> Option<&'static str> {
+        Some(EXAMPLE)
+    }
+
+    fn parse(input: &str) -> Result<Vec<Game>, Box<dyn Error>> {
+        let cubes_set_sep = ';';
+        let cube_items_sep = ',';
+        let mut games = Vec::new();
+
+        for line in lines(input) {
+            let (game, sets_text) = line.split_once(":")?;
+            let id = match game.trim().strip_prefix("Game ") {
+                Some(id) => line.parse(id)?,
+                None => return Err(line.error(game, "expected `Game <id>`").into()),
+            };
+            let mut sets = Vec::new();
+
+            for part in sets_text.split_terminator(cubes_set_sep) {
+                let mut set = CubeSet::default();
+                let cube_items = part.split(cube_items_sep);
+                for cube_item in cube_items {
+                    let cube_item = cube_item.trim();
+                    let (value, color) = cube_item
+                        .split_once(' ')
+                        .ok_or_else(|| line.error(cube_item, "expected `<count> <color>`"))?;
+                    let value: i32 = line.parse(value)?;
+
+                    match color {
+                        "red" => set.red = value,
+                        "green" => set.green = value,
+                        "blue" => set.blue = value,
+                        _ => return Err(line.error(color, "unknown color").into()),
+                    }
+                }
+                sets.push(set);
+            }
+
+            games.push(Game { id, sets });
+        }
+
+        Ok(games)
+    }
+
+    fn part1(games: &Vec<Game>) -> i32 {
+        let max_red = 12;
+        let max_green = 13;
+        let max_blue = 14;
+
+        let mut sum_of_possible_games_ids = 0;
+
+        for game in games {
+            let possible = game
+                .sets
+                .iter()
+                .all(|set| set.red <= max_red && set.green <= max_green && set.blue <= max_blue);
+
+            step::checkpoint("game", || format!("game {} possible: {possible}", game.id));
+            if possible {
+                debug!(game = game.id, "possible");
+                sum_of_possible_games_ids += game.id;
+            }
+        }
+
+        sum_of_possible_games_ids
+    }
+
+    fn part2(games: &Vec<Game>) -> i32 {
+        let mut sum_of_set_power = 0;
+
+        for game in games {
+            let mut max_red = 0;
+            let mut max_green = 0;
+            let mut max_blue = 0;
+
+            for set in &game.sets {
+                max_red = cmp::max(max_red, set.red);
+                max_green = cmp::max(max_green, set.green);
+                max_blue = cmp::max(max_blue, set.blue);
+            }
+
+            let power = max_red * max_green * max_blue;
+            debug!(
+                game = game.id,
+                red = max_red,
+                green = max_green,
+                blue = max_blue,
+                power,
+                "minimum set"
+            );
+            step::checkpoint("game", || {
+                format!(
+                    "game {}: {max_red} red, {max_green} green, {max_blue} blue, power {power}",
+                    game.id
+                )
+            });
+            sum_of_set_power += power;
+        }
+
+        sum_of_set_power
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part1_example() {
+        let games = Day2::parse(EXAMPLE).unwrap();
+        assert_eq!(Day2::part1(&games), 8);
+    }
+
+    #[test]
+    fn part2_example() {
+        let games = Day2::parse(EXAMPLE).unwrap();
+        assert_eq!(Day2::part2(&games), 2286);
+    }
+
+    #[test]
+    fn limits_are_inclusive() {
+        let games = Day2::parse("Game 7: 12 red, 13 green, 14 blue\nGame 8: 15 blue").unwrap();
+        assert_eq!(Day2::part1(&games), 7);
+    }
+
+    #[test]
+    fn missing_color_counts_as_zero_for_power() {
+        let games = Day2::parse("Game 1: 3 red; 5 green").unwrap();
+        assert_eq!(Day2::part2(&games), 0);
+    }
+
+    #[test]
+    fn unknown_color_is_an_error() {
+        let err = Day2::parse("Game 1: 3 blue, 4 purple").err().unwrap();
+        assert_eq!(
+            err.to_string(),
+            "line 1, column 19: unknown color: `purple`"
+        );
+    }
+}
@@ -0,0 +1,173 @@
+use aoc_core::{step, Grid, Part, Pos, Solution};
+use std::collections::BTreeSet;
+use std::error::Error;
+use tracing::{debug, trace};
+
+const EXAMPLE: &str = "\
+467..114..
+...*......
+..35..633.
+......#...
+617*......
+.....+.58.
+..592.....
+......755.
+...$.*....
+.664.598..
+";
+
+pub struct Day3;
+
+pub struct Schematic {
+    pub grid: Grid<char>,
+    /// Every part number, in reading order.
+    pub numbers: Vec<i64>,
+    /// For each cell, the index in `numbers` of the number it is a digit of.
+    pub number_at: Grid<Option<usize>>,
+}
+
+impl Schematic {
+    fn symbols(&self) -> impl Iterator<Item = (Pos, char)> + '_ {
+        self.grid
+            .iter()
+            .filter(|(_, c)| !c.is_ascii_digit() && **c != '.')
+            .map(|(pos, c)| (pos, *c))
+    }
+
+    /// The distinct numbers touching `pos`, diagonals included.
+    fn adjacent_numbers(&self, pos: Pos) -> BTreeSet<usize> {
+        self.grid
+            .neighbors8(pos)
+            .filter_map(|neighbor| self.number_at[neighbor])
+            .collect()
+    }
+}
+
+impl Solution for Day3 {
+    const YEAR: u32 = 2023;
+    const DAY: u32 = 3;
+
+    type Model = Schematic;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn example(_: Part) -> Option<&'static str> {
+        Some(EXAMPLE)
+    }
+
+    fn parse(input: &str) -> Result<Schematic, Box<dyn Error>> {
+        let grid = Grid::parse(input, Some)?;
+        let mut numbers = Vec::new();
+        let mut number_at = Grid::new(grid.rows(), grid.cols(), None);
+
+        for (row, cells) in grid.iter_rows().enumerate() {
+            let mut current: Option<usize> = None;
+
+            for (col, c) in cells.iter().enumerate() {
+                match c.to_digit(10) {
+                    Some(digit) => {
+                        let index = *current.get_or_insert_with(|| {
+                            numbers.push(0);
+                            numbers.len() - 1
+                        });
+                        numbers[index] = numbers[index] * 10 + i64::from(digit);
+                        number_at[(row, col)] = Some(index);
+                    }
+                    None => current = None,
+                }
+            }
+        }
+
+        let schematic = Schematic {
+            grid,
+            numbers,
+            number_at,
+        };
+
+        debug!(
+            rows = schematic.grid.rows(),
+            cols = schematic.grid.cols(),
+            numbers = schematic.numbers.len(),
+            symbols = schematic.symbols().count(),
+            "parsed schematic"
+        );
+
+        Ok(schematic)
+    }
+
+    fn part1(schematic: &Schematic) -> i64 {
+        let part_numbers: BTreeSet<usize> = schematic
+            .symbols()
+            .flat_map(|(pos, symbol)| {
+                trace!(?pos, %symbol, "found symbol");
+                schematic.adjacent_numbers(pos)
+            })
+            .collect();
+
+        part_numbers
+            .into_iter()
+            .map(|index| schematic.numbers[index])
+            .sum()
+    }
+
+    fn part2(schematic: &Schematic) -> i64 {
+        let mut sum = 0;
+
+        for (pos, c) in schematic.symbols() {
+            if c != '*' {
+                continue;
+            }
+
+            let neighbours = schematic.adjacent_numbers(pos);
+            trace!(?pos, ?neighbours, "gear candidate");
+            step::checkpoint("gear", || {
+                let numbers: Vec<i64> = neighbours.iter().map(|&i| schematic.numbers[i]).collect();
+                format!("* at {pos:?} touches {numbers:?}")
+            });
+
+            if neighbours.len() == 2 {
+                sum += neighbours
+                    .into_iter()
+                    .map(|index| schematic.numbers[index])
+                    .product::<i64>();
+            }
+        }
+
+        sum
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part1_example() {
+        let schematic = Day3::parse(EXAMPLE).unwrap();
+        assert_eq!(Day3::part1(&schematic), 4361);
+    }
+
+    #[test]
+    fn part2_example() {
+        let schematic = Day3::parse(EXAMPLE).unwrap();
+        assert_eq!(Day3::part2(&schematic), 467835);
+    }
+
+    #[test]
+    fn number_touching_two_symbols_counts_once() {
+        let schematic = Day3::parse("*12#").unwrap();
+        assert_eq!(Day3::part1(&schematic), 12);
+    }
+
+    #[test]
+    fn numbers_at_line_ends_and_diagonals() {
+        let schematic = Day3::parse("..7\n.+.\n5..").unwrap();
+        assert_eq!(Day3::part1(&schematic), 12);
+    }
+
+    #[test]
+    fn gear_needs_exactly_two_numbers() {
+        let schematic = Day3::parse("2*.\n...\n1*3\n.4.").unwrap();
+        assert_eq!(Day3::part2(&schematic), 0);
+    }
+}
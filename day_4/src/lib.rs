@@ -0,0 +1,138 @@
+use aoc_core::{lines, step, Part, Solution};
+use std::collections::HashSet;
+use std::error::Error;
+use tracing::{debug, trace};
+
+const EXAMPLE: &str = "\
+Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
+Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
+Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
+Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11
+";
+
+pub struct Day4;
+
+pub struct Card {
+    pub name: String,
+    pub winner_numbers: Vec<i32>,
+    pub owner_numbers: Vec<i32>,
+}
+
+impl Card {
+    /// How many of the owner's numbers are winning numbers.
+    pub fn matches(&self) -> usize {
+        let winner_set: HashSet<_> = self.winner_numbers.iter().cloned().collect();
+        let owner_set: HashSet<_> = self.owner_numbers.iter().cloned().collect();
+
+        owner_set.intersection(&winner_set).count()
+    }
+}
+
+impl Solution for Day4 {
+    const YEAR: u32 = 2023;
+    const DAY: u32 = 4;
+
+    type Model = Vec<Card>;
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn example(_: Part) -> Option<&'static str> {
+        Some(EXAMPLE)
+    }
+
+    fn parse(input: &str) -> Result<Vec<Card>, Box<dyn Error>> {
+        let mut cards = Vec::new();
+
+        for line in lines(input) {
+            let (name, numbers) = line.split_once(":")?;
+            let (winner_numbers, owner_numbers) = numbers
+                .split_once('|')
+                .ok_or_else(|| line.missing("expected `|`"))?;
+
+            cards.push(Card {
+                name: name.to_string(),
+                winner_numbers: line.numbers(winner_numbers)?,
+                owner_numbers: line.numbers(owner_numbers)?,
+            });
+        }
+
+        Ok(cards)
+    }
+
+    fn part1(cards: &Vec<Card>) -> i32 {
+        let mut sum = 0;
+
+        for card in cards {
+            let points: i32 = match card.matches() {
+                0 => 0,
+                x => u32::pow(2, (x as i32 - 1) as u32) as i32,
+            };
+
+            debug!(card = %card.name, points);
+            step::checkpoint("card", || format!("{}: {points} points", card.name));
+            sum += points;
+        }
+
+        sum
+    }
+
+    fn part2(cards: &Vec<Card>) -> i32 {
+        let mut cards_count = Vec::new();
+
+        for (card, matches) in cards.iter().map(Card::matches).enumerate() {
+            if cards_count.len() <= card {
+                cards_count.push(1);
+            }
+
+            for j in (card + 1)..(matches + card + 1) {
+                if cards_count.len() <= j {
+                    cards_count.push(1);
+                }
+                cards_count[j] += cards_count[card];
+                trace!(card, copy = j, count = cards_count[j], "won copies");
+            }
+            step::checkpoint("card", || {
+                format!(
+                    "card {}: {matches} matches, copies so far {cards_count:?}",
+                    card + 1
+                )
+            });
+        }
+
+        cards_count.into_iter().sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part1_example() {
+        let cards = Day4::parse(EXAMPLE).unwrap();
+        assert_eq!(Day4::part1(&cards), 13);
+    }
+
+    #[test]
+    fn part2_example() {
+        let cards = Day4::parse(EXAMPLE).unwrap();
+        assert_eq!(Day4::part2(&cards), 30);
+    }
+
+    #[test]
+    fn card_without_matches_scores_nothing() {
+        let cards = Day4::parse("Card 1: 1 2 | 3 4").unwrap();
+        assert_eq!(Day4::part1(&cards), 0);
+        assert_eq!(Day4::part2(&cards), 1);
+    }
+
+    #[test]
+    fn parses_padded_numbers() {
+        let cards = Day4::parse("Card   9:  1 21 | 21  1  5").unwrap();
+        assert_eq!(cards[0].winner_numbers, [1, 21]);
+        assert_eq!(cards[0].owner_numbers, [21, 1, 5]);
+        assert_eq!(cards[0].matches(), 2);
+    }
+}
@@ -0,0 +1,480 @@
+mod piecewise;
+
+pub use piecewise::{Piece, PiecewiseMap};
+
+use aoc_core::{lines, search, step, Params, ParseError, Part, RangeSet, Solution};
+use std::collections::HashSet;
+use std::error::Error;
+use std::fmt;
+use std::ops::Range;
+use tracing::{debug, trace};
+
+const EXAMPLE: &str = "\
+seeds: 79 14 55 13
+
+seed-to-soil map:
+50 98 2
+52 50 48
+
+soil-to-fertilizer map:
+0 15 37
+37 52 2
+39 0 15
+
+fertilizer-to-water map:
+49 53 8
+0 11 42
+42 0 7
+57 7 4
+
+water-to-light map:
+88 18 7
+18 25 70
+
+light-to-temperature map:
+45 77 23
+81 45 19
+68 64 13
+
+temperature-to-humidity map:
+0 69 1
+1 0 69
+
+humidity-to-location map:
+60 56 37
+56 93 4
+";
+
+pub struct Day5;
+
+pub struct MapRange {
+    pub src: i64,
+    pub dst: i64,
+    pub range: i64,
+}
+
+pub struct Map {
+    pub from: String,
+    pub to: String,
+    pub ranges: Vec<MapRange>,
+}
+
+pub struct Almanac {
+    pub seeds: Vec<i64>,
+    /// Maps in file order.
+    pub maps: Vec<Map>,
+    /// Category the seed numbers are in, `seed` unless `--set from=<name>`.
+    pub from: String,
+    /// Category the answers are in, `location` unless `--set to=<name>`.
+    pub to: String,
+    /// Indexes into `maps` leading from `from` to `to`.
+    pub path: Vec<usize>,
+}
+
+/// A problem with how the almanac's maps connect its categories.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AlmanacError {
+    UnknownCategory(String),
+    NoPath { from: String, to: String },
+    Cycle(Vec<String>),
+}
+
+impl fmt::Display for AlmanacError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AlmanacError::UnknownCategory(name) => write!(f, "unknown category `{name}`"),
+            AlmanacError::NoPath { from, to } => {
+                write!(f, "no chain of maps leads from `{from}` to `{to}`")
+            }
+            AlmanacError::Cycle(categories) => {
+                write!(f, "maps form a cycle: {}", categories.join(" -> "))
+            }
+        }
+    }
+}
+
+impl Error for AlmanacError {}
+
+impl Solution for Day5 {
+    const YEAR: u32 = 2023;
+    const DAY: u32 = 5;
+
+    type Model = Almanac;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn example(_: Part) -> Option<&'static str> {
+        Some(EXAMPLE)
+    }
+
+    fn parse(input: &str) -> Result<Almanac, Box<dyn Error>> {
+        Self::parse_with(input, &Params::new())
+    }
+
+    fn parse_with(input: &str, params: &Params) -> Result<Almanac, Box<dyn Error>> {
+        params.check(&["from", "to"])?;
+
+        let mut seeds: Option<Vec<i64>> = None;
+        let mut maps = Vec::new();
+        let mut last_map: Option<Map> = None;
+
+        for line in lines(input) {
+            match line.text {
+                "" => {
+                    if let Some(mapping) = last_map.take() {
+                        maps.push(mapping);
+                    }
+                }
+                x if x.starts_with("seeds:") => {
+                    seeds = Some(line.numbers(&x["seeds:".len()..])?);
+                }
+                x if x.ends_with("map:") => {
+                    let name = x.trim_end_matches("map:").trim();
+                    let (from, to) = name
+                        .split_once("-to-")
+                        .filter(|(from, to)| !from.is_empty() && !to.is_empty())
+                        .ok_or_else(|| line.error(x, "expected `<from>-to-<to> map:`"))?;
+
+                    last_map = Some(Map {
+                        from: from.to_string(),
+                        to: to.to_string(),
+                        ranges: Vec::new(),
+                    })
+                }
+
+                x => {
+                    let numbers: Vec<i64> = line.numbers(x)?;
+                    let [dst, src, range] = numbers[..] else {
+                        return Err(line
+                            .error(x, "expected `<destination> <source> <length>`")
+                            .into());
+                    };
+
+                    match last_map.as_mut() {
+                        Some(last_map) => last_map.ranges.push(MapRange { src, dst, range }),
+                        None => return Err(line.error(x, "range outside of a map").into()),
+                    }
+                }
+            }
+        }
+
+        if let Some(mapping) = last_map {
+            maps.push(mapping);
+        }
+
+        let seeds = seeds.ok_or_else(|| ParseError::new(1, 1, "", "missing `seeds:` line"))?;
+
+        if let Some(cycle) = find_cycle(&maps) {
+            return Err(AlmanacError::Cycle(cycle).into());
+        }
+
+        let from = params.get("from")?.unwrap_or_else(|| "seed".to_string());
+        let to = params.get("to")?.unwrap_or_else(|| "location".to_string());
+        let path = find_path(&maps, &from, &to)?;
+        debug!(%from, %to, maps = path.len(), "found category path");
+
+        Ok(Almanac {
+            seeds,
+            maps,
+            from,
+            to,
+            path,
+        })
+    }
+
+    fn part1(almanac: &Almanac) -> i64 {
+        let seed_to_location = almanac.compose();
+
+        almanac
+            .seeds
+            .iter()
+            .map(|&seed| {
+                let location = seed_to_location.get(seed);
+                trace!(seed, location, "converted seed");
+                location
+            })
+            .min()
+            .unwrap_or_default()
+    }
+
+    fn part2(almanac: &Almanac) -> i64 {
+        let seeds = almanac.seed_ranges();
+        debug!(%seeds, "converting seed ranges");
+
+        almanac.compose().min_over(&seeds).unwrap_or_default()
+    }
+}
+
+impl Almanac {
+    /// The seeds read as `<start> <length>` pairs.
+    pub fn seed_ranges(&self) -> RangeSet {
+        self.seeds
+            .chunks(2)
+            .map(|pair| pair[0]..pair[0] + pair.get(1).copied().unwrap_or(1))
+            .collect()
+    }
+
+    /// The maps from `from` to `to` applied in turn, as one function.
+    pub fn compose(&self) -> PiecewiseMap {
+        let composed = self
+            .path
+            .iter()
+            .fold(PiecewiseMap::identity(), |composed, &map| {
+                let map = &self.maps[map];
+                let composed = composed.then(&map.to_piecewise());
+                step::checkpoint("map", || {
+                    format!("{} to {}:\n{composed}", self.from, map.to)
+                });
+                composed
+            });
+        debug!(pieces = composed.pieces().len(), "composed almanac");
+        trace!(%composed);
+
+        composed
+    }
+}
+
+/// Indexes of the maps converting `from` into `to`, fewest maps first.
+fn find_path(maps: &[Map], from: &str, to: &str) -> Result<Vec<usize>, AlmanacError> {
+    for category in [from, to] {
+        if !maps
+            .iter()
+            .any(|map| map.from == category || map.to == category)
+        {
+            return Err(AlmanacError::UnknownCategory(category.to_string()));
+        }
+    }
+
+    let search = search::bfs(from, |&category| {
+        maps.iter()
+            .filter(move |map| map.from == category)
+            .map(|map| map.to.as_str())
+    });
+    let categories = search.path_to(&to).ok_or_else(|| AlmanacError::NoPath {
+        from: from.to_string(),
+        to: to.to_string(),
+    })?;
+
+    let path = categories
+        .windows(2)
+        .map(|step| {
+            maps.iter()
+                .position(|map| map.from == step[0] && map.to == step[1])
+                .unwrap()
+        })
+        .collect();
+
+    Ok(path)
+}
+
+/// The categories of a cycle of maps, starting and ending with the same one.
+fn find_cycle(maps: &[Map]) -> Option<Vec<String>> {
+    // Categories on the current walk, in order; a map back into one of them
+    // closes a cycle.
+    fn visit<'a>(
+        category: &'a str,
+        maps: &'a [Map],
+        walk: &mut Vec<&'a str>,
+        done: &mut HashSet<&'a str>,
+    ) -> Option<Vec<String>> {
+        if let Some(start) = walk.iter().position(|&c| c == category) {
+            let mut cycle: Vec<String> = walk[start..].iter().map(|c| c.to_string()).collect();
+            cycle.push(category.to_string());
+            return Some(cycle);
+        }
+        if !done.insert(category) {
+            return None;
+        }
+
+        walk.push(category);
+        for map in maps.iter().filter(|map| map.from == category) {
+            if let Some(cycle) = visit(&map.to, maps, walk, done) {
+                return Some(cycle);
+            }
+        }
+        walk.pop();
+
+        None
+    }
+
+    let mut done = HashSet::new();
+    maps.iter()
+        .find_map(|map| visit(&map.from, maps, &mut Vec::new(), &mut done))
+}
+
+impl MapRange {
+    /// The values this range maps, as a half-open range.
+    pub fn source(&self) -> Range<i64> {
+        self.src..self.src + self.range
+    }
+
+    pub fn offset(&self) -> i64 {
+        self.dst - self.src
+    }
+}
+
+impl Map {
+    /// The map as a function; where sources overlap, the earlier range wins.
+    pub fn to_piecewise(&self) -> PiecewiseMap {
+        PiecewiseMap::new(self.ranges.iter().map(|range| Piece {
+            source: range.source(),
+            offset: range.offset(),
+        }))
+    }
+
+    /// Converts every value of `values`; values outside all of the map's
+    /// ranges keep their number.
+    pub fn apply(&self, values: &RangeSet) -> RangeSet {
+        let sources: Vec<Range<i64>> = self.ranges.iter().map(MapRange::source).collect();
+        let (parts, unmapped) = values.split(&sources);
+
+        parts
+            .iter()
+            .zip(&self.ranges)
+            .fold(unmapped, |converted, (part, range)| {
+                converted.union(&part.shift(range.offset()))
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn params(settings: &[&str]) -> Params {
+        let mut params = Params::new();
+        for setting in settings {
+            params.extend(setting.parse().unwrap());
+        }
+        params
+    }
+
+    #[test]
+    fn part1_example() {
+        let almanac = Day5::parse(EXAMPLE).unwrap();
+        assert_eq!(Day5::part1(&almanac), 35);
+    }
+
+    #[test]
+    fn part2_example() {
+        let almanac = Day5::parse(EXAMPLE).unwrap();
+        assert_eq!(Day5::part2(&almanac), 46);
+    }
+
+    #[test]
+    fn parses_maps_in_order() {
+        let almanac = Day5::parse(EXAMPLE).unwrap();
+        assert_eq!(almanac.seeds, [79, 14, 55, 13]);
+        assert_eq!(almanac.maps.len(), 7);
+        assert_eq!(almanac.maps[0].from, "seed");
+        assert_eq!(almanac.maps[6].to, "location");
+        assert_eq!(almanac.maps[2].ranges.len(), 4);
+    }
+
+    #[test]
+    fn map_range_end_is_exclusive() {
+        let almanac = Day5::parse("seeds: 10 5\n\nseed-to-location map:\n100 5 5").unwrap();
+        assert_eq!(Day5::part1(&almanac), 10);
+    }
+
+    #[test]
+    fn seed_range_straddling_two_map_ranges() {
+        let input = "seeds: 8 4\n\nseed-to-location map:\n50 5 5\n20 10 5";
+        let almanac = Day5::parse(input).unwrap();
+        assert_eq!(Day5::part2(&almanac), 20);
+    }
+
+    #[test]
+    fn seed_range_straddling_mapped_and_unmapped_values() {
+        // 3 and 4 are not mapped and keep their number; 5 and 6 become 100
+        // and 101.
+        let input = "seeds: 3 4\n\nseed-to-location map:\n100 5 10";
+        let almanac = Day5::parse(input).unwrap();
+        assert_eq!(Day5::part2(&almanac), 3);
+
+        let seeds = RangeSet::from(3..7);
+        assert_eq!(almanac.maps[0].apply(&seeds).ranges(), [3..5, 100..102]);
+    }
+
+    #[test]
+    fn composed_almanac_matches_map_by_map() {
+        let almanac = Day5::parse(EXAMPLE).unwrap();
+        let composed = almanac.compose();
+
+        for seed in 0..110 {
+            let mut values = RangeSet::from(seed..seed + 1);
+            for map in &almanac.maps {
+                values = map.apply(&values);
+            }
+            assert_eq!(
+                RangeSet::from(composed.get(seed)..composed.get(seed) + 1),
+                values
+            );
+        }
+    }
+
+    #[test]
+    fn reverse_lookup_from_location_to_seed() {
+        let almanac = Day5::parse(EXAMPLE).unwrap();
+        let composed = almanac.compose();
+
+        // The example's answers: seed 13 goes to location 35, and 82 to 46.
+        let location_to_seed = composed.inverse().unwrap();
+        assert_eq!(location_to_seed.get(35), 13);
+        assert_eq!(location_to_seed.get(46), 82);
+        assert!(composed.preimage(&RangeSet::from(46..47)).contains(82));
+    }
+
+    #[test]
+    fn converts_between_any_two_categories() {
+        let almanac = Day5::parse_with(EXAMPLE, &params(&["from=soil", "to=humidity"])).unwrap();
+        assert_eq!(almanac.path, [1, 2, 3, 4, 5]);
+
+        // Soil 81 is fertilizer 81, water 81, light 74, temperature 78 and
+        // humidity 78.
+        assert_eq!(almanac.compose().get(81), 78);
+
+        let almanac = Day5::parse_with(EXAMPLE, &params(&["from=soil"])).unwrap();
+        assert_eq!(almanac.to, "location");
+        assert_eq!(almanac.path.len(), 6);
+    }
+
+    #[test]
+    fn category_names_may_contain_to() {
+        let input = "seeds: 1 1\n\npotato-to-tomato map:\n5 1 1";
+        let err = Day5::parse_with(input, &params(&["from=potato"]))
+            .err()
+            .unwrap();
+        assert_eq!(err.to_string(), "unknown category `location`");
+
+        let almanac = Day5::parse_with(input, &params(&["from=potato", "to=tomato"])).unwrap();
+        assert_eq!(
+            (&*almanac.maps[0].from, &*almanac.maps[0].to),
+            ("potato", "tomato")
+        );
+        assert_eq!(Day5::part1(&almanac), 5);
+    }
+
+    #[test]
+    fn missing_links_and_cycles_are_errors() {
+        let input = "seeds: 1 1\n\nseed-to-soil map:\n\nwater-to-location map:\n";
+        let err = Day5::parse(input).err().unwrap();
+        assert_eq!(
+            err.to_string(),
+            "no chain of maps leads from `seed` to `location`"
+        );
+
+        let input = "seeds: 1 1\n\nseed-to-a map:\n\na-to-b map:\n\nb-to-a map:\n";
+        let err = Day5::parse(input).err().unwrap();
+        assert_eq!(err.to_string(), "maps form a cycle: a -> b -> a");
+    }
+
+    #[test]
+    fn malformed_range_is_an_error() {
+        let err = Day5::parse("seeds: 1 2\n\na-to-b map:\n1 2").err().unwrap();
+        assert_eq!(
+            err.to_string(),
+            "line 4, column 1: expected `<destination> <source> <length>`: `1 2`"
+        );
+    }
+}
@@ -0,0 +1,286 @@
+use aoc_core::RangeSet;
+use std::fmt;
+use std::ops::Range;
+
+/// Values in `source` are moved by `offset`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Piece {
+    pub source: Range<i64>,
+    pub offset: i64,
+}
+
+impl Piece {
+    /// Where `source` ends up.
+    pub fn image(&self) -> Range<i64> {
+        self.source.start + self.offset..self.source.end + self.offset
+    }
+}
+
+/// A function on integers that moves each of a set of disjoint ranges by its
+/// own offset and keeps every other value.
+///
+/// Pieces are kept normalized: sorted, non-overlapping, none with offset 0,
+/// and neighbours with the same offset merged, so equal functions compare
+/// equal.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct PiecewiseMap {
+    pieces: Vec<Piece>,
+}
+
+impl PiecewiseMap {
+    /// The function that keeps every value.
+    pub fn identity() -> PiecewiseMap {
+        PiecewiseMap::default()
+    }
+
+    /// Builds a map from pieces in priority order: where sources overlap,
+    /// the earlier piece wins.
+    pub fn new(pieces: impl IntoIterator<Item = Piece>) -> PiecewiseMap {
+        let mut covered = RangeSet::new();
+        let mut kept = Vec::new();
+
+        for piece in pieces {
+            let uncovered = RangeSet::from(piece.source.clone()).difference(&covered);
+            for source in uncovered.ranges() {
+                kept.push(Piece {
+                    source: source.clone(),
+                    offset: piece.offset,
+                });
+            }
+            covered.insert(piece.source);
+        }
+
+        PiecewiseMap::normalize(kept)
+    }
+
+    /// Sorts disjoint pieces, drops identity ones and merges touching ones
+    /// with the same offset.
+    fn normalize(mut pieces: Vec<Piece>) -> PiecewiseMap {
+        pieces.retain(|piece| piece.offset != 0 && !piece.source.is_empty());
+        pieces.sort_unstable_by_key(|piece| piece.source.start);
+
+        let mut merged: Vec<Piece> = Vec::with_capacity(pieces.len());
+        for piece in pieces {
+            match merged.last_mut() {
+                Some(last)
+                    if last.source.end == piece.source.start && last.offset == piece.offset =>
+                {
+                    last.source.end = piece.source.end;
+                }
+                _ => merged.push(piece),
+            }
+        }
+
+        PiecewiseMap { pieces: merged }
+    }
+
+    pub fn pieces(&self) -> &[Piece] {
+        &self.pieces
+    }
+
+    pub fn get(&self, value: i64) -> i64 {
+        let index = self
+            .pieces
+            .partition_point(|piece| piece.source.end <= value);
+        match self.pieces.get(index) {
+            Some(piece) if piece.source.contains(&value) => value + piece.offset,
+            _ => value,
+        }
+    }
+
+    /// `range` cut where pieces start and end, each part with its offset;
+    /// parts outside every piece have offset 0.
+    fn segments(&self, range: Range<i64>) -> Vec<(Range<i64>, i64)> {
+        let mut segments = Vec::new();
+        let mut start = range.start;
+
+        let first = self
+            .pieces
+            .partition_point(|piece| piece.source.end <= start);
+        for piece in &self.pieces[first..] {
+            if start >= range.end || piece.source.start >= range.end {
+                break;
+            }
+            if piece.source.start > start {
+                segments.push((start..piece.source.start, 0));
+                start = piece.source.start;
+            }
+            let end = piece.source.end.min(range.end);
+            segments.push((start..end, piece.offset));
+            start = end;
+        }
+        if start < range.end {
+            segments.push((start..range.end, 0));
+        }
+
+        segments
+    }
+
+    /// The smallest range holding every source and image, outside of which
+    /// the function is the identity on both sides.
+    fn bounds(&self) -> Option<Range<i64>> {
+        let ends = self
+            .pieces
+            .iter()
+            .flat_map(|piece| [piece.source.clone(), piece.image()]);
+        let start = ends.clone().map(|range| range.start).min()?;
+        let end = ends.map(|range| range.end).max()?;
+        Some(start..end)
+    }
+
+    /// The function applying `self`, then `next`.
+    pub fn then(&self, next: &PiecewiseMap) -> PiecewiseMap {
+        let bounds = match (self.bounds(), next.bounds()) {
+            (Some(a), Some(b)) => a.start.min(b.start)..a.end.max(b.end),
+            (Some(bounds), None) | (None, Some(bounds)) => bounds,
+            (None, None) => return PiecewiseMap::identity(),
+        };
+
+        let mut pieces = Vec::new();
+        for (source, offset) in self.segments(bounds) {
+            let image = source.start + offset..source.end + offset;
+            for (part, next_offset) in next.segments(image) {
+                pieces.push(Piece {
+                    source: part.start - offset..part.end - offset,
+                    offset: offset + next_offset,
+                });
+            }
+        }
+
+        PiecewiseMap::normalize(pieces)
+    }
+
+    /// Where the values of `values` end up.
+    pub fn image(&self, values: &RangeSet) -> RangeSet {
+        values
+            .ranges()
+            .iter()
+            .flat_map(|range| self.segments(range.clone()))
+            .map(|(range, offset)| range.start + offset..range.end + offset)
+            .collect()
+    }
+
+    /// Every value that ends up in `values`.
+    pub fn preimage(&self, values: &RangeSet) -> RangeSet {
+        let mut preimage = values.difference(&self.sources());
+        for piece in &self.pieces {
+            let hit = values.intersection(&RangeSet::from(piece.image()));
+            preimage = preimage.union(&hit.shift(-piece.offset));
+        }
+
+        preimage
+    }
+
+    /// The smallest value `values` are mapped to.
+    pub fn min_over(&self, values: &RangeSet) -> Option<i64> {
+        self.image(values).min()
+    }
+
+    /// The inverse function, if this one is a bijection.
+    pub fn inverse(&self) -> Option<PiecewiseMap> {
+        let sources = self.sources();
+        let images: RangeSet = self.pieces.iter().map(Piece::image).collect();
+        let moved: i64 = self
+            .pieces
+            .iter()
+            .map(|piece| piece.source.end - piece.source.start)
+            .sum();
+
+        // The moved values must land on distinct values, exactly the ones
+        // they vacated.
+        if images.len() != moved || images != sources {
+            return None;
+        }
+
+        let pieces = self.pieces.iter().map(|piece| Piece {
+            source: piece.image(),
+            offset: -piece.offset,
+        });
+        Some(PiecewiseMap::normalize(pieces.collect()))
+    }
+
+    fn sources(&self) -> RangeSet {
+        self.pieces
+            .iter()
+            .map(|piece| piece.source.clone())
+            .collect()
+    }
+}
+
+impl fmt::Display for PiecewiseMap {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.pieces.is_empty() {
+            return write!(f, "identity");
+        }
+        for (i, piece) in self.pieces.iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            write!(
+                f,
+                "{}..{} {:+}",
+                piece.source.start, piece.source.end, piece.offset
+            )?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn map(pieces: &[(Range<i64>, i64)]) -> PiecewiseMap {
+        PiecewiseMap::new(pieces.iter().map(|(source, offset)| Piece {
+            source: source.clone(),
+            offset: *offset,
+        }))
+    }
+
+    #[test]
+    fn earlier_pieces_win_and_pieces_merge() {
+        let f = map(&[(0..5, 10), (3..8, 10), (8..9, 0), (20..30, -1)]);
+        assert_eq!(f.to_string(), "0..8 +10\n20..30 -1");
+        assert_eq!((f.get(4), f.get(8), f.get(20), f.get(40)), (14, 8, 19, 40));
+        assert_eq!(PiecewiseMap::identity().to_string(), "identity");
+    }
+
+    #[test]
+    fn composition_matches_applying_in_turn() {
+        let f = map(&[(0..10, 5), (10..15, -10)]);
+        let g = map(&[(3..8, 100), (12..20, -12)]);
+        let composed = f.then(&g);
+
+        for x in -5..30 {
+            assert_eq!(composed.get(x), g.get(f.get(x)), "at {x}");
+        }
+        assert_eq!(PiecewiseMap::identity().then(&g), g);
+        assert_eq!(f.then(&PiecewiseMap::identity()), f);
+    }
+
+    #[test]
+    fn image_and_preimage() {
+        let f = map(&[(0..10, 5), (10..15, -10)]);
+        let values = RangeSet::from(8..12);
+
+        assert_eq!(f.image(&values).ranges(), [0..2, 13..15]);
+        assert_eq!(f.min_over(&values), Some(0));
+        assert_eq!(f.preimage(&f.image(&values)), values);
+
+        // 6 itself moves away to 11, so only 1 lands on 6.
+        assert_eq!(f.preimage(&RangeSet::from(6..7)), RangeSet::from(1..2));
+    }
+
+    #[test]
+    fn inverse_of_a_bijection() {
+        // Swaps 0..5 with 5..10.
+        let f = map(&[(0..5, 5), (5..10, -5)]);
+        let inverse = f.inverse().unwrap();
+        for x in -3..13 {
+            assert_eq!(inverse.get(f.get(x)), x);
+        }
+
+        // Both 0..5 and 5..10 end up on 5..10.
+        assert_eq!(map(&[(0..5, 5)]).inverse(), None);
+    }
+}
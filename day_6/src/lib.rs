@@ -0,0 +1,268 @@
+use aoc_core::math::isqrt;
+use aoc_core::{lines, step, Line, ParseError, Part, Solution};
+use std::error::Error;
+use std::iter::zip;
+use tracing::{debug, trace};
+
+const EXAMPLE: &str = "\
+Time:      7  15   30
+Distance:  9  40  200
+";
+
+pub struct Day6;
+
+pub struct Race {
+    pub time: u128,
+    pub distance: u128,
+}
+
+pub struct Races {
+    pub races: Vec<Race>,
+    /// Every race read as one, with the spaces between numbers ignored.
+    pub long_race: Race,
+}
+
+impl Solution for Day6 {
+    const YEAR: u32 = 2023;
+    const DAY: u32 = 6;
+
+    type Model = Races;
+    type Answer1 = u128;
+    type Answer2 = u128;
+
+    fn example(_: Part) -> Option<&'static str> {
+        Some(EXAMPLE)
+    }
+
+    fn parse(input: &str) -> Result<Races, Box<dyn Error>> {
+        let mut lines_iter = lines(input);
+        let mut numbers =
+            |number: usize, label: &str| -> Result<(Line, Vec<u128>, u128), ParseError> {
+                let line = lines_iter.next().ok_or_else(|| {
+                    ParseError::new(number, 1, "", format!("missing `{label}` line"))
+                })?;
+                let Some(numbers) = line.text.strip_prefix(label) else {
+                    return Err(line.error(line.text, format!("expected `{label}`")));
+                };
+
+                let values = line.numbers(numbers)?;
+
+                // The kerning is wrong: all the races are really one long race.
+                let joined: String = values.iter().map(u128::to_string).collect();
+                let joined = if joined.is_empty() {
+                    0
+                } else {
+                    joined.parse().map_err(|_| {
+                        line.error(numbers.trim(), "races joined into one are too long")
+                    })?
+                };
+
+                Ok((line, values, joined))
+            };
+
+        let (_, timings, time) = numbers(1, "Time:")?;
+        let (line, distances, distance) = numbers(2, "Distance:")?;
+        if distances.len() != timings.len() {
+            return Err(line
+                .missing(format!(
+                    "expected {} distances, one per race, found {}",
+                    timings.len(),
+                    distances.len()
+                ))
+                .into());
+        }
+
+        Ok(Races {
+            races: zip(timings, distances)
+                .map(|(time, distance)| Race { time, distance })
+                .collect(),
+            long_race: Race { time, distance },
+        })
+    }
+
+    fn part1(races: &Races) -> u128 {
+        races
+            .races
+            .iter()
+            .map(|race| {
+                let ways = ways_to_win(race.time, race.distance);
+                trace!(time = race.time, distance = race.distance, ways);
+                step::checkpoint("race", || {
+                    format!("{}ms to beat {}mm: {ways} ways", race.time, race.distance)
+                });
+                ways
+            })
+            .product()
+    }
+
+    fn part2(races: &Races) -> u128 {
+        let Race { time, distance } = races.long_race;
+        debug!(time, distance, "one long race");
+
+        ways_to_win(time, distance)
+    }
+}
+
+/// How many whole milliseconds of holding the button beat `distance` in a
+/// race of `time`.
+///
+/// Holding for `h` goes `h * (time - h)`, which grows until `time / 2` and
+/// is symmetric around it, so the winning holds run from the first one that
+/// beats `distance` to its mirror image. The first one is near the smaller
+/// root of `h * (time - h) = distance`, from an integer square root of the
+/// discriminant, and is then moved onto the exact boundary. When `time` is
+/// too large to square, it is found by binary search instead. A product too
+/// large for a `u128` certainly beats `distance`.
+pub fn ways_to_win(time: u128, distance: u128) -> u128 {
+    let beats = |hold: u128| {
+        hold.checked_mul(time - hold)
+            .is_none_or(|gone| gone > distance)
+    };
+
+    let half = time / 2;
+    if !beats(half) {
+        return 0;
+    }
+
+    // Beating `distance` at all means `time * time > 4 * distance`, so the
+    // discriminant cannot underflow once the square fits.
+    let mut first = match time.checked_mul(time) {
+        Some(square) => (time - isqrt(square - 4 * distance)) / 2,
+        None => first_win(half, beats),
+    };
+    while first > 0 && beats(first - 1) {
+        first -= 1;
+    }
+    while !beats(first) {
+        first += 1;
+    }
+
+    time - 2 * first + 1
+}
+
+/// The smallest hold up to `high` that `beats`, given that `high` does and
+/// that every hold after the first winning one also does.
+fn first_win(high: u128, beats: impl Fn(u128) -> bool) -> u128 {
+    let (mut low, mut high) = (0, high);
+    // `beats(high)` holds throughout; every hold below `low` loses.
+    while low < high {
+        let mid = low + (high - low) / 2;
+        if beats(mid) {
+            high = mid;
+        } else {
+            low = mid + 1;
+        }
+    }
+    low
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part1_example() {
+        let races = Day6::parse(EXAMPLE).unwrap();
+        assert_eq!(Day6::part1(&races), 288);
+    }
+
+    #[test]
+    fn part2_example() {
+        let races = Day6::parse(EXAMPLE).unwrap();
+        assert_eq!(Day6::part2(&races), 71503);
+    }
+
+    #[test]
+    fn tying_the_record_does_not_win() {
+        // Holding for 2ms of a 4ms race goes exactly 4mm.
+        let races = Day6::parse("Time: 4\nDistance: 4").unwrap();
+        assert_eq!(Day6::part1(&races), 0);
+        assert_eq!(Day6::part2(&races), 0);
+    }
+
+    fn brute_force(time: u128, distance: u128) -> u128 {
+        (0..=time)
+            .filter(|hold| hold * (time - hold) > distance)
+            .count() as u128
+    }
+
+    #[test]
+    fn matches_brute_force() {
+        for time in 0..80 {
+            for distance in 0..=time * time / 4 + 2 {
+                assert_eq!(
+                    ways_to_win(time, distance),
+                    brute_force(time, distance),
+                    "time {time}, distance {distance}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn exact_for_large_races() {
+        // Roots at 10^18 +- 1, so only the hold right between them wins.
+        let time = 2 * 10u128.pow(18);
+        let distance = 10u128.pow(36) - 1;
+        assert_eq!(ways_to_win(time, distance), 1);
+        assert_eq!(ways_to_win(time, distance - 1), 3);
+    }
+
+    #[test]
+    fn square_root_estimate_matches_binary_search() {
+        let largest_square = u64::MAX as u128;
+        for time in [7, 30, 71530, 2 * 10u128.pow(18) + 1, largest_square] {
+            for distance in [0, 9, time * time / 5, time * time / 4 - 1, time * time / 4] {
+                let beats = |hold: u128| hold * (time - hold) > distance;
+                let expected = if beats(time / 2) {
+                    time - 2 * first_win(time / 2, beats) + 1
+                } else {
+                    0
+                };
+                assert_eq!(ways_to_win(time, distance), expected, "{time} {distance}");
+            }
+        }
+    }
+
+    #[test]
+    fn races_too_long_to_square() {
+        // 2 * 10^22 ms squared does not fit in a u128.
+        let races = Day6::parse("Time: 99999999999 99999999999\nDistance: 1 1").unwrap();
+        let time = 10u128.pow(22) - 1;
+        assert_eq!(races.long_race.time, time);
+        assert_eq!(Day6::part2(&races), time - 1);
+
+        let err = Day6::parse("Time: 1 340282366920938463463374607431768211455\nDistance: 1 1")
+            .err()
+            .unwrap();
+        assert_eq!(
+            err.to_string(),
+            "line 1, column 7: races joined into one are too long: \
+             `1 340282366920938463463374607431768211455`"
+        );
+    }
+
+    #[test]
+    fn negative_numbers_are_an_error() {
+        let err = Day6::parse("Time: 7 -15\nDistance: 9 40").err().unwrap();
+        assert_eq!(err.to_string(), "line 1, column 9: invalid number: `-15`");
+    }
+
+    #[test]
+    fn mismatched_race_counts_are_an_error() {
+        let err = Day6::parse("Time: 7 8\nDistance: 9").err().unwrap();
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 12: expected 2 distances, one per race, found 1"
+        );
+    }
+
+    #[test]
+    fn missing_distances_is_an_error() {
+        let err = Day6::parse("Time: 7 15").err().unwrap();
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 1: missing `Distance:` line"
+        );
+    }
+}
@@ -0,0 +1,313 @@
+mod report;
+mod rules;
+
+pub use report::{HandReport, Report, ReportFormat, Stats};
+pub use rules::{HandType, RuleSet, Rules, TieBreak};
+
+use aoc_core::{lines, step, ParamError, Params, Part, Solution};
+use std::error::Error;
+use std::fs::File;
+use std::io::BufWriter;
+use tracing::{info, trace};
+
+const EXAMPLE: &str = "\
+32T3K 765
+T55J5 684
+KK677 28
+KTJJT 220
+QQQJA 483
+";
+
+/// Every card label, in no particular order.
+const CARDS: &str = "AKQJT98765432";
+
+pub struct Day7;
+
+pub struct Hand {
+    pub cards: String,
+    pub bid: i64,
+}
+
+impl Solution for Day7 {
+    const YEAR: u32 = 2023;
+    const DAY: u32 = 7;
+
+    type Model = Vec<Hand>;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn example(_: Part) -> Option<&'static str> {
+        Some(EXAMPLE)
+    }
+
+    fn parse(input: &str) -> Result<Vec<Hand>, Box<dyn Error>> {
+        let mut hands = Vec::new();
+
+        for line in lines(input) {
+            let (cards, bid) = line.split_once(" ")?;
+
+            if cards.chars().count() != 5 {
+                return Err(line.error(cards, "a hand has exactly five cards").into());
+            }
+            if let Some(card) = cards.matches(|c| !CARDS.contains(c)).next() {
+                return Err(line.error(card, "invalid card").into());
+            }
+
+            hands.push(Hand {
+                cards: cards.to_string(),
+                bid: line.parse(bid)?,
+            });
+        }
+
+        Ok(hands)
+    }
+
+    /// Takes `report=<file>`, a `.csv` or `.json` file for [`Day7::export`].
+    fn parse_with(input: &str, params: &Params) -> Result<Vec<Hand>, Box<dyn Error>> {
+        params.check(&["report"])?;
+        report_path(params)?;
+        Self::parse(input)
+    }
+
+    /// Writes a report on both variants to the `report` file, if given.
+    fn export(hands: &Vec<Hand>, params: &Params) -> Result<(), Box<dyn Error>> {
+        let Some((path, format)) = report_path(params)? else {
+            return Ok(());
+        };
+
+        let reports = [
+            Report::new("standard", hands, &RuleSet::standard()),
+            Report::new("jokers", hands, &RuleSet::jokers()),
+        ];
+        for report in &reports {
+            info!(
+                variant = report.variant,
+                upgraded = report.stats.upgraded,
+                total_winnings = report.stats.total_winnings,
+                types = ?report.stats.types,
+                "hand report"
+            );
+        }
+
+        let mut out = BufWriter::new(File::create(&path)?);
+        format.write(&reports, &mut out)?;
+        info!(path, "wrote hand report");
+        Ok(())
+    }
+
+    fn part1(hands: &Vec<Hand>) -> i64 {
+        total_winnings(hands, &RuleSet::standard())
+    }
+
+    fn part2(hands: &Vec<Hand>) -> i64 {
+        total_winnings(hands, &RuleSet::jokers())
+    }
+}
+
+/// The `report` file and its format, picked by the extension.
+fn report_path(params: &Params) -> Result<Option<(String, ReportFormat)>, ParamError> {
+    let Some(path) = params.get::<String>("report")? else {
+        return Ok(None);
+    };
+    match ReportFormat::from_path(&path) {
+        Some(format) => Ok(Some((path, format))),
+        None => Err(ParamError::InvalidValue {
+            key: "report".to_string(),
+            value: path,
+        }),
+    }
+}
+
+impl Hand {
+    pub fn hand_type(&self, rules: &impl Rules) -> HandType {
+        rules.hand_type(&self.cards)
+    }
+}
+
+/// `hands` ordered by `rules`, weakest first.
+pub fn rank<'a>(hands: &'a [Hand], rules: &impl Rules) -> Vec<&'a Hand> {
+    let mut ranked: Vec<&Hand> = hands.iter().collect();
+    ranked.sort_by(|a, b| rules.compare(&a.cards, &b.cards));
+    ranked
+}
+
+/// Ranks `hands` under `rules`, weakest first, and adds up each bid times
+/// its rank.
+pub fn total_winnings(hands: &[Hand], rules: &impl Rules) -> i64 {
+    rank(hands, rules)
+        .iter()
+        .enumerate()
+        .map(|(i, hand)| {
+            let rank = i as i64 + 1;
+            let winnings = hand.bid * rank;
+            trace!(
+                hand = %hand.cards,
+                hand_type = %hand.hand_type(rules),
+                bid = hand.bid,
+                rank,
+                winnings
+            );
+            step::checkpoint("hand", || {
+                format!(
+                    "{} ({}) bids {}, rank {rank}, wins {winnings}",
+                    hand.cards,
+                    hand.hand_type(rules),
+                    hand.bid
+                )
+            });
+            winnings
+        })
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cmp::Ordering;
+
+    #[test]
+    fn part1_example() {
+        let hands = Day7::parse(EXAMPLE).unwrap();
+        assert_eq!(Day7::part1(&hands), 6440);
+    }
+
+    #[test]
+    fn part2_example() {
+        let hands = Day7::parse(EXAMPLE).unwrap();
+        assert_eq!(Day7::part2(&hands), 5905);
+    }
+
+    #[test]
+    fn hand_types() {
+        let rules = RuleSet::standard();
+        assert_eq!(rules.hand_type("AAAAA"), HandType::FiveOfAKind);
+        assert_eq!(rules.hand_type("AA8AA"), HandType::FourOfAKind);
+        assert_eq!(rules.hand_type("23332"), HandType::FullHouse);
+        assert_eq!(rules.hand_type("TTT98"), HandType::ThreeOfAKind);
+        assert_eq!(rules.hand_type("23432"), HandType::TwoPair);
+        assert_eq!(rules.hand_type("A23A4"), HandType::OnePair);
+        assert_eq!(rules.hand_type("23456"), HandType::HighCard);
+        assert!(HandType::FullHouse > HandType::ThreeOfAKind);
+    }
+
+    #[test]
+    fn jokers_take_the_best_type() {
+        let rules = RuleSet::jokers();
+        assert_eq!(rules.hand_type("JJJJJ"), HandType::FiveOfAKind);
+        assert_eq!(rules.hand_type("JJJJ2"), HandType::FiveOfAKind);
+        assert_eq!(rules.hand_type("QJJQ2"), HandType::FourOfAKind);
+        assert_eq!(rules.hand_type("2233J"), HandType::FullHouse);
+        assert_eq!(rules.hand_type("2234J"), HandType::ThreeOfAKind);
+        assert_eq!(rules.hand_type("2345J"), HandType::OnePair);
+    }
+
+    #[test]
+    fn custom_rules() {
+        // Deuces wild, with poker-style tie breaks on the sorted cards.
+        let rules = RuleSet {
+            card_order: "23456789TJQKA".to_string(),
+            wildcards: "2".to_string(),
+            tie_break: TieBreak::Sorted,
+        };
+        assert_eq!(rules.hand_type("2KK2Q"), HandType::FourOfAKind);
+        assert_eq!(rules.compare("A3456", "6543A"), Ordering::Equal);
+        assert_eq!(rules.compare("K3456", "6543A"), Ordering::Less);
+
+        // The same hands in order: A3456 leads with the stronger card.
+        let rules = RuleSet::standard();
+        assert_eq!(rules.compare("A3456", "6543A"), Ordering::Greater);
+    }
+
+    #[test]
+    fn five_jokers_beat_four_of_a_kind_but_lose_ties() {
+        let hands = Day7::parse("JJJJJ 10\nAAAAK 1").unwrap();
+        assert_eq!(Day7::part2(&hands), 21);
+
+        // Both are five of a kind, and J is the weakest card.
+        let hands = Day7::parse("JJJJJ 10\n2222J 1").unwrap();
+        assert_eq!(Day7::part2(&hands), 12);
+    }
+
+    #[test]
+    fn substitutions() {
+        let rules = RuleSet::jokers();
+        assert_eq!(rules.substitute("QJJQ2"), Some('Q'));
+        assert_eq!(rules.substitute("23J32"), Some('3'));
+        assert_eq!(rules.substitute("JJJJJ"), Some('A'));
+        assert_eq!(rules.substitute("23456"), None);
+        assert_eq!(RuleSet::standard().substitute("QJJQ2"), None);
+    }
+
+    #[test]
+    fn report_stats() {
+        let hands = Day7::parse(EXAMPLE).unwrap();
+
+        let report = Report::new("standard", &hands, &RuleSet::standard());
+        assert_eq!(report.stats.total_winnings, 6440);
+        assert_eq!(report.stats.upgraded, 0);
+        assert_eq!(report.stats.types[&HandType::ThreeOfAKind], 2);
+
+        let report = Report::new("jokers", &hands, &RuleSet::jokers());
+        assert_eq!(report.stats.total_winnings, 5905);
+        assert_eq!(report.stats.upgraded, 3);
+        assert_eq!(report.stats.types[&HandType::FourOfAKind], 3);
+        assert_eq!(report.stats.types[&HandType::FiveOfAKind], 0);
+
+        let strongest = report.hands.last().unwrap();
+        assert_eq!(
+            (
+                strongest.cards.as_str(),
+                strongest.substitute,
+                strongest.rank
+            ),
+            ("KTJJT", Some('T'), 5)
+        );
+        assert_eq!(strongest.natural_type, HandType::TwoPair);
+    }
+
+    #[test]
+    fn report_exports() {
+        let hands = Day7::parse("QJJQ2 3\n23456 1").unwrap();
+        let reports = [Report::new("jokers", &hands, &RuleSet::jokers())];
+
+        let mut csv = Vec::new();
+        ReportFormat::Csv.write(&reports, &mut csv).unwrap();
+        assert_eq!(
+            String::from_utf8(csv).unwrap(),
+            "variant,rank,cards,bid,type,natural_type,substitute,winnings\n\
+             jokers,1,23456,1,high card,high card,,1\n\
+             jokers,2,QJJQ2,3,four of a kind,two pair,Q,6\n"
+        );
+
+        let mut json = Vec::new();
+        ReportFormat::Json.write(&reports, &mut json).unwrap();
+        let json: serde_json::Value = serde_json::from_slice(&json).unwrap();
+        assert_eq!(json[0]["hands"][1]["type"], "four of a kind");
+        assert_eq!(json[0]["hands"][1]["substitute"], "Q");
+        assert_eq!(json[0]["stats"]["types"]["two pair"], 0);
+        assert_eq!(json[0]["stats"]["upgraded"], 1);
+    }
+
+    #[test]
+    fn report_format_comes_from_the_extension() {
+        assert_eq!(ReportFormat::from_path("out.csv"), Some(ReportFormat::Csv));
+        assert_eq!(
+            ReportFormat::from_path("out/hands.json"),
+            Some(ReportFormat::Json)
+        );
+        assert_eq!(ReportFormat::from_path("report"), None);
+
+        let params: Params = "report=hands.txt".parse().unwrap();
+        let err = Day7::parse_with(EXAMPLE, &params).err().unwrap();
+        assert_eq!(
+            err.to_string(),
+            "invalid value `hands.txt` for parameter `report`"
+        );
+    }
+
+    #[test]
+    fn invalid_card_is_an_error() {
+        let err = Day7::parse("32T3K 765\nKK6Z7 28").err().unwrap();
+        assert_eq!(err.to_string(), "line 2, column 4: invalid card: `Z`");
+    }
+}
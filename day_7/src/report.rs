@@ -0,0 +1,138 @@
+use crate::{rank, Hand, HandType, Rules};
+use serde::{Serialize, Serializer};
+use std::collections::BTreeMap;
+use std::io::{self, Write};
+
+/// One hand's share of the total winnings under one set of rules.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct HandReport {
+    pub rank: usize,
+    pub cards: String,
+    pub bid: i64,
+    #[serde(rename = "type")]
+    pub hand_type: HandType,
+    /// The type with wildcards taken at face value.
+    pub natural_type: HandType,
+    /// What the wildcards stood for, if the hand has any.
+    pub substitute: Option<char>,
+    pub winnings: i64,
+}
+
+impl HandReport {
+    /// Whether wildcards made the hand stronger.
+    pub fn upgraded(&self) -> bool {
+        self.hand_type > self.natural_type
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Stats {
+    /// How many hands have each type, weakest type first.
+    pub types: BTreeMap<HandType, usize>,
+    /// Hands whose wildcards made them stronger.
+    pub upgraded: usize,
+    pub total_winnings: i64,
+}
+
+/// Every hand of one variant, weakest first, with its statistics.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Report {
+    pub variant: String,
+    pub hands: Vec<HandReport>,
+    pub stats: Stats,
+}
+
+impl Report {
+    pub fn new(variant: &str, hands: &[Hand], rules: &impl Rules) -> Report {
+        let hands: Vec<HandReport> = rank(hands, rules)
+            .into_iter()
+            .enumerate()
+            .map(|(i, hand)| HandReport {
+                rank: i + 1,
+                cards: hand.cards.clone(),
+                bid: hand.bid,
+                hand_type: hand.hand_type(rules),
+                natural_type: HandType::of(&hand.cards),
+                substitute: rules.substitute(&hand.cards),
+                winnings: hand.bid * (i as i64 + 1),
+            })
+            .collect();
+
+        let mut types: BTreeMap<HandType, usize> = HandType::ALL
+            .into_iter()
+            .map(|hand_type| (hand_type, 0))
+            .collect();
+        for hand in &hands {
+            *types.entry(hand.hand_type).or_default() += 1;
+        }
+
+        let stats = Stats {
+            types,
+            upgraded: hands.iter().filter(|hand| hand.upgraded()).count(),
+            total_winnings: hands.iter().map(|hand| hand.winnings).sum(),
+        };
+
+        Report {
+            variant: variant.to_string(),
+            hands,
+            stats,
+        }
+    }
+}
+
+/// How `--set report=<file>` writes reports, picked by the file extension.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReportFormat {
+    /// One row per hand and variant; the statistics are left out.
+    Csv,
+    /// Every report with its statistics.
+    Json,
+}
+
+impl ReportFormat {
+    pub fn from_path(path: &str) -> Option<ReportFormat> {
+        match path.rsplit_once('.')?.1 {
+            "csv" => Some(ReportFormat::Csv),
+            "json" => Some(ReportFormat::Json),
+            _ => None,
+        }
+    }
+
+    pub fn write(self, reports: &[Report], out: &mut impl Write) -> io::Result<()> {
+        match self {
+            ReportFormat::Csv => {
+                writeln!(
+                    out,
+                    "variant,rank,cards,bid,type,natural_type,substitute,winnings"
+                )?;
+                for report in reports {
+                    for hand in &report.hands {
+                        writeln!(
+                            out,
+                            "{},{},{},{},{},{},{},{}",
+                            report.variant,
+                            hand.rank,
+                            hand.cards,
+                            hand.bid,
+                            hand.hand_type,
+                            hand.natural_type,
+                            hand.substitute.map(String::from).unwrap_or_default(),
+                            hand.winnings
+                        )?;
+                    }
+                }
+                Ok(())
+            }
+            ReportFormat::Json => {
+                serde_json::to_writer_pretty(&mut *out, reports)?;
+                writeln!(out)
+            }
+        }
+    }
+}
+
+impl Serialize for HandType {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.name())
+    }
+}
@@ -0,0 +1,196 @@
+use std::cmp::{Ordering, Reverse};
+use std::fmt;
+
+/// Hand types from weakest to strongest.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum HandType {
+    HighCard,
+    OnePair,
+    TwoPair,
+    ThreeOfAKind,
+    FullHouse,
+    FourOfAKind,
+    FiveOfAKind,
+}
+
+impl HandType {
+    pub const ALL: [HandType; 7] = [
+        HandType::HighCard,
+        HandType::OnePair,
+        HandType::TwoPair,
+        HandType::ThreeOfAKind,
+        HandType::FullHouse,
+        HandType::FourOfAKind,
+        HandType::FiveOfAKind,
+    ];
+
+    /// The type of a hand whose labels occur `counts` times, largest first.
+    pub fn from_counts(counts: &[usize]) -> HandType {
+        match counts {
+            [5, ..] => HandType::FiveOfAKind,
+            [4, ..] => HandType::FourOfAKind,
+            [3, 2, ..] => HandType::FullHouse,
+            [3, ..] => HandType::ThreeOfAKind,
+            [2, 2, ..] => HandType::TwoPair,
+            [2, ..] => HandType::OnePair,
+            _ => HandType::HighCard,
+        }
+    }
+
+    /// The type of `cards` taken at face value, with no wildcards.
+    pub fn of(cards: &str) -> HandType {
+        let mut counts: Vec<(char, usize)> = Vec::new();
+        for card in cards.chars() {
+            match counts.iter_mut().find(|(label, _)| *label == card) {
+                Some((_, count)) => *count += 1,
+                None => counts.push((card, 1)),
+            }
+        }
+
+        let mut counts: Vec<usize> = counts.into_iter().map(|(_, count)| count).collect();
+        counts.sort_unstable_by_key(|&count| Reverse(count));
+        HandType::from_counts(&counts)
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            HandType::HighCard => "high card",
+            HandType::OnePair => "one pair",
+            HandType::TwoPair => "two pair",
+            HandType::ThreeOfAKind => "three of a kind",
+            HandType::FullHouse => "full house",
+            HandType::FourOfAKind => "four of a kind",
+            HandType::FiveOfAKind => "five of a kind",
+        }
+    }
+}
+
+impl fmt::Display for HandType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// How hands of the same type are ordered.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TieBreak {
+    /// Compare the first cards, then the second ones, and so on.
+    #[default]
+    InOrder,
+    /// Compare the strongest cards, then the next strongest, and so on.
+    Sorted,
+}
+
+/// A variant of Camel Cards: how hands are typed and ranked.
+pub trait Rules {
+    /// Every card label from weakest to strongest.
+    fn card_order(&self) -> &str;
+
+    /// Labels that stand in for whichever card makes the best hand. They
+    /// keep their own strength when breaking ties.
+    fn wildcards(&self) -> &str {
+        ""
+    }
+
+    fn tie_break(&self) -> TieBreak {
+        TieBreak::InOrder
+    }
+
+    /// How strong `card` is on its own; higher is stronger.
+    fn strength(&self, card: char) -> usize {
+        self.card_order().find(card).unwrap_or(0)
+    }
+
+    /// The label the wildcards in `cards` become: the most common other
+    /// label, the strongest of equally common ones, or the strongest card
+    /// when every card is wild. `None` when `cards` has no wildcards.
+    fn substitute(&self, cards: &str) -> Option<char> {
+        let is_wild = |card: char| self.wildcards().contains(card);
+        if !cards.chars().any(is_wild) {
+            return None;
+        }
+
+        cards
+            .chars()
+            .filter(|&card| !is_wild(card))
+            .max_by_key(|&card| (cards.matches(card).count(), self.strength(card)))
+            .or_else(|| self.card_order().chars().last())
+    }
+
+    /// The best type `cards` can make, with the wildcards substituted.
+    fn hand_type(&self, cards: &str) -> HandType {
+        match self.substitute(cards) {
+            Some(substitute) => {
+                let cards: String = cards
+                    .chars()
+                    .map(|card| {
+                        if self.wildcards().contains(card) {
+                            substitute
+                        } else {
+                            card
+                        }
+                    })
+                    .collect();
+                HandType::of(&cards)
+            }
+            None => HandType::of(cards),
+        }
+    }
+
+    /// Orders two hands, weakest first.
+    fn compare(&self, a: &str, b: &str) -> Ordering {
+        let strengths = |cards: &str| {
+            let mut strengths: Vec<usize> = cards.chars().map(|card| self.strength(card)).collect();
+            if self.tie_break() == TieBreak::Sorted {
+                strengths.sort_unstable_by_key(|&strength| Reverse(strength));
+            }
+            strengths
+        };
+
+        self.hand_type(a)
+            .cmp(&self.hand_type(b))
+            .then_with(|| strengths(a).cmp(&strengths(b)))
+    }
+}
+
+/// Rules given as data, for the standard and joker variants or any other.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RuleSet {
+    pub card_order: String,
+    pub wildcards: String,
+    pub tie_break: TieBreak,
+}
+
+impl RuleSet {
+    /// Part 1: no wildcards, J is a jack.
+    pub fn standard() -> RuleSet {
+        RuleSet {
+            card_order: "23456789TJQKA".to_string(),
+            wildcards: String::new(),
+            tie_break: TieBreak::InOrder,
+        }
+    }
+
+    /// Part 2: J is a joker, wild but the weakest card on its own.
+    pub fn jokers() -> RuleSet {
+        RuleSet {
+            card_order: "J23456789TQKA".to_string(),
+            wildcards: "J".to_string(),
+            tie_break: TieBreak::InOrder,
+        }
+    }
+}
+
+impl Rules for RuleSet {
+    fn card_order(&self) -> &str {
+        &self.card_order
+    }
+
+    fn wildcards(&self) -> &str {
+        &self.wildcards
+    }
+
+    fn tie_break(&self) -> TieBreak {
+        self.tie_break
+    }
+}
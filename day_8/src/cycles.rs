@@ -0,0 +1,219 @@
+use aoc_core::math::{self, CrtError};
+use std::collections::HashMap;
+use std::fmt;
+use std::hash::Hash;
+
+/// The shape of a deterministic walk: it runs `tail` steps, then repeats a
+/// loop of `length` steps forever.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cycle {
+    /// Steps before the walk first enters its loop.
+    pub tail: u64,
+    /// Steps the loop takes to come back to the same state.
+    pub length: u64,
+    /// Steps before `tail` at which the walk is on an end state.
+    pub tail_hits: Vec<u64>,
+    /// How far into the loop the walk is on an end state, each below
+    /// `length`; the walk is there at `tail + offset + k * length` for
+    /// every `k`.
+    pub offsets: Vec<u64>,
+}
+
+impl Cycle {
+    /// Walks from `start` with `next` until a state repeats. States must
+    /// hold everything `next` depends on, e.g. the node and the position in
+    /// the instructions.
+    pub fn find<S: Hash + Eq + Clone>(
+        start: S,
+        mut next: impl FnMut(&S) -> S,
+        is_end: impl Fn(&S) -> bool,
+    ) -> Cycle {
+        let mut seen: HashMap<S, u64> = HashMap::new();
+        let mut hits = Vec::new();
+        let mut state = start;
+        let mut step = 0;
+
+        let tail = loop {
+            if let Some(&first) = seen.get(&state) {
+                break first;
+            }
+            if is_end(&state) {
+                hits.push(step);
+            }
+            let following = next(&state);
+            seen.insert(state, step);
+            state = following;
+            step += 1;
+        };
+
+        let split = hits.partition_point(|&hit| hit < tail);
+        Cycle {
+            tail,
+            length: step - tail,
+            offsets: hits[split..].iter().map(|hit| hit - tail).collect(),
+            tail_hits: hits[..split].to_vec(),
+        }
+    }
+
+    /// Whether the walk is on an end state after `step` steps.
+    pub fn is_end_at(&self, step: u64) -> bool {
+        if step < self.tail {
+            self.tail_hits.binary_search(&step).is_ok()
+        } else {
+            let offset = (step - self.tail) % self.length;
+            self.offsets.binary_search(&offset).is_ok()
+        }
+    }
+
+    /// The first step at which the walk is on an end state.
+    pub fn first_end(&self) -> Option<u64> {
+        self.tail_hits
+            .first()
+            .copied()
+            .or_else(|| Some(self.tail + self.offsets.first()?))
+    }
+
+    pub fn never_ends(&self) -> bool {
+        self.tail_hits.is_empty() && self.offsets.is_empty()
+    }
+}
+
+/// Why walks never are on end states all at once.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CycleError {
+    /// The walk with this index never reaches an end state.
+    NeverEnds(usize),
+    /// Every walk reaches end states, but never on the same step.
+    NeverTogether,
+    /// The first common step does not fit in 64 bits.
+    Overflow,
+}
+
+impl fmt::Display for CycleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CycleError::NeverEnds(walk) => write!(f, "walk {walk} never reaches an end"),
+            CycleError::NeverTogether => write!(f, "the walks never reach ends on the same step"),
+            CycleError::Overflow => write!(f, "the walks meet too late to count"),
+        }
+    }
+}
+
+impl std::error::Error for CycleError {}
+
+/// The first step at which every walk is on an end state.
+///
+/// Once every walk is in its loop, each offset gives a congruence, so the
+/// answer is the smallest solution over every choice of one offset per walk.
+/// Before that, only the tail hits of the walk with the longest tail can be
+/// common.
+pub fn first_common(cycles: &[Cycle]) -> Result<u64, CycleError> {
+    if let Some(walk) = cycles.iter().position(Cycle::never_ends) {
+        return Err(CycleError::NeverEnds(walk));
+    }
+    let Some(latest) = cycles.iter().max_by_key(|cycle| cycle.tail) else {
+        return Ok(0);
+    };
+
+    if let Some(&step) = latest
+        .tail_hits
+        .iter()
+        .find(|&&step| cycles.iter().all(|cycle| cycle.is_end_at(step)))
+    {
+        return Ok(step);
+    }
+
+    // Each entry is a step `r` modulo `m`, so every walk is on an end state
+    // at steps `r + k * m` past the tails.
+    let mut congruences: Vec<(u64, u64)> = vec![(0, 1)];
+    for cycle in cycles {
+        let mut combined = Vec::new();
+        for &congruence in &congruences {
+            for &offset in &cycle.offsets {
+                match math::crt([congruence, (cycle.tail + offset, cycle.length)]) {
+                    Ok(congruence) => combined.push(congruence),
+                    Err(CrtError::Inconsistent) => {}
+                    Err(CrtError::Overflow) => return Err(CycleError::Overflow),
+                }
+            }
+        }
+        combined.sort_unstable();
+        combined.dedup();
+        congruences = combined;
+    }
+
+    let tail = latest.tail;
+    congruences
+        .into_iter()
+        .map(|(r, m)| {
+            if r >= tail {
+                Some(r)
+            } else {
+                ((tail - r).div_ceil(m)).checked_mul(m)?.checked_add(r)
+            }
+        })
+        // A step that overflows comes after every step that does not.
+        .min_by_key(|step| (step.is_none(), *step))
+        .ok_or(CycleError::NeverTogether)?
+        .ok_or(CycleError::Overflow)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A walk around `0..n` that is on an end state at `ends`.
+    fn ring(start: u64, n: u64, ends: &[u64]) -> Cycle {
+        Cycle::find(start, |&s| (s + 1) % n, |s| ends.contains(s))
+    }
+
+    #[test]
+    fn finds_tail_and_loop() {
+        // 0 -> 1 -> 2 -> 3 -> 4 -> 2 -> ...
+        let cycle = Cycle::find(
+            0u32,
+            |&s| if s == 4 { 2 } else { s + 1 },
+            |&s| s == 1 || s == 3,
+        );
+        assert_eq!(cycle.tail, 2);
+        assert_eq!(cycle.length, 3);
+        assert_eq!(cycle.tail_hits, [1]);
+        assert_eq!(cycle.offsets, [1]);
+
+        assert_eq!(cycle.first_end(), Some(1));
+
+        let hits: Vec<u64> = (0..10).filter(|&step| cycle.is_end_at(step)).collect();
+        assert_eq!(hits, [1, 3, 6, 9]);
+    }
+
+    #[test]
+    fn combines_offsets_beyond_lcm() {
+        // Ends at steps 2, 6, 10, ... and at 3 and 4 modulo 6: no step is 2
+        // modulo 4 and 3 modulo 6, but 10 is 4 modulo 6.
+        let a = ring(0, 4, &[2]);
+        let b = ring(0, 6, &[3, 4]);
+        assert_eq!(first_common(&[a.clone(), b]), Ok(10));
+        assert_eq!(
+            first_common(&[a, ring(0, 6, &[3])]),
+            Err(CycleError::NeverTogether)
+        );
+    }
+
+    #[test]
+    fn tail_hits_can_be_common() {
+        let cycle = Cycle::find(0u32, |&s| if s == 3 { 1 } else { s + 1 }, |&s| s == 0);
+        assert_eq!((cycle.tail, cycle.length, cycle.offsets.len()), (1, 3, 0));
+        assert_eq!(first_common(&[cycle.clone(), ring(0, 5, &[0])]), Ok(0));
+        assert_eq!(
+            first_common(&[cycle, ring(1, 5, &[0])]),
+            Err(CycleError::NeverTogether)
+        );
+    }
+
+    #[test]
+    fn never_ending_walk_is_explained() {
+        let err = first_common(&[ring(0, 3, &[1]), ring(0, 3, &[])]).unwrap_err();
+        assert_eq!(err, CycleError::NeverEnds(1));
+        assert_eq!(err.to_string(), "walk 1 never reaches an end");
+    }
+}
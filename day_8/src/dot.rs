@@ -0,0 +1,76 @@
+use crate::Network;
+use aoc_core::search;
+use std::collections::BTreeSet;
+use std::fmt::Write;
+
+/// Renders `network` as a Graphviz digraph, keeping only the nodes reachable
+/// from `starts` if there are any.
+///
+/// Start nodes, the ones ending in A and those in `starts`, are filled green
+/// and end nodes, ending in Z, red. Edges are labelled with the instruction
+/// that follows them, and both instructions share one edge when they lead to
+/// the same node.
+pub fn to_dot(network: &Network, starts: &[u32]) -> String {
+    let nodes: BTreeSet<u32> = if starts.is_empty() {
+        (0..network.len() as u32).collect()
+    } else {
+        starts
+            .iter()
+            .flat_map(|&start| {
+                let search = search::bfs(start, |&node| network.links[node as usize]);
+                search
+                    .distances()
+                    .map(|(&node, _)| node)
+                    .collect::<Vec<_>>()
+            })
+            .collect()
+    };
+
+    let mut dot = String::from("digraph network {\n");
+    for &node in &nodes {
+        let name = network.name(node);
+        let id = quote(name);
+        let color = if starts.contains(&node) || name.ends_with('A') {
+            Some("palegreen")
+        } else if name.ends_with('Z') {
+            Some("salmon")
+        } else {
+            None
+        };
+        match color {
+            Some(color) => writeln!(dot, "    {id} [style=filled, fillcolor={color}];").unwrap(),
+            None => writeln!(dot, "    {id};").unwrap(),
+        }
+    }
+
+    for &node in &nodes {
+        let id = quote(network.name(node));
+        let [left, right] = network.links[node as usize];
+        let edges = if left == right {
+            vec![(left, "LR")]
+        } else {
+            vec![(left, "L"), (right, "R")]
+        };
+        for (target, label) in edges {
+            let target = quote(network.name(target));
+            writeln!(dot, "    {id} -> {target} [label=\"{label}\"];").unwrap();
+        }
+    }
+    dot.push_str("}\n");
+
+    dot
+}
+
+/// `name` as a DOT string, with quotes and backslashes escaped.
+fn quote(name: &str) -> String {
+    let mut quoted = String::with_capacity(name.len() + 2);
+    quoted.push('"');
+    for c in name.chars() {
+        if c == '"' || c == '\\' {
+            quoted.push('\\');
+        }
+        quoted.push(c);
+    }
+    quoted.push('"');
+    quoted
+}
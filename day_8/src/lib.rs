@@ -0,0 +1,461 @@
+mod cycles;
+mod dot;
+
+pub use cycles::{first_common, Cycle, CycleError};
+pub use dot::to_dot;
+
+use aoc_core::{lines, step, ParamError, Params, ParseError, Part, Solution};
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt;
+use std::fs;
+use tracing::{debug, info, trace, Level};
+
+const EXAMPLE_1: &str = "\
+RL
+
+AAA = (BBB, CCC)
+BBB = (DDD, EEE)
+CCC = (ZZZ, GGG)
+DDD = (DDD, DDD)
+EEE = (EEE, EEE)
+GGG = (GGG, GGG)
+ZZZ = (ZZZ, ZZZ)
+";
+
+const EXAMPLE_2: &str = "\
+LR
+
+11A = (11B, XXX)
+11B = (XXX, 11Z)
+11Z = (11B, XXX)
+22A = (22B, XXX)
+22B = (22C, 22C)
+22C = (22Z, 22Z)
+22Z = (22B, 22B)
+XXX = (XXX, XXX)
+";
+
+pub struct Day8;
+
+/// The network with node names interned: nodes are dense indices into
+/// `links`, and `name`/`node` translate back and forth.
+pub struct Network {
+    /// `0` for left and `1` for right.
+    pub left_right_intructions: Vec<usize>,
+    /// The left and right neighbours of each node.
+    pub links: Vec<[u32; 2]>,
+    names: Vec<String>,
+    indices: HashMap<String, u32>,
+}
+
+impl Network {
+    /// How many nodes there are; nodes are `0..len`.
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+
+    pub fn name(&self, node: u32) -> &str {
+        &self.names[node as usize]
+    }
+
+    /// The node called `name`, if there is one.
+    pub fn node(&self, name: &str) -> Option<u32> {
+        self.indices.get(name).copied()
+    }
+
+    /// Where `direction`, `0` for left and `1` for right, leads from `node`.
+    pub fn next(&self, node: u32, direction: usize) -> u32 {
+        self.links[node as usize][direction]
+    }
+
+    /// Every node whose name ends with `suffix`, in input order.
+    pub fn nodes_ending_with<'a>(&'a self, suffix: &'a str) -> impl Iterator<Item = u32> + 'a {
+        (0..self.len() as u32).filter(move |&node| self.name(node).ends_with(suffix))
+    }
+}
+
+impl Solution for Day8 {
+    const YEAR: u32 = 2023;
+    const DAY: u32 = 8;
+
+    type Model = Network;
+    type Answer1 = Meeting;
+    type Answer2 = Meeting;
+
+    fn example(part: Part) -> Option<&'static str> {
+        match part {
+            Part::One => Some(EXAMPLE_1),
+            Part::Two => Some(EXAMPLE_2),
+        }
+    }
+
+    fn parse(input: &str) -> Result<Network, Box<dyn Error>> {
+        let mut lines = lines(input);
+        let left = 0;
+        let right = 1;
+
+        let instructions = lines
+            .next()
+            .filter(|line| !line.text.is_empty())
+            .ok_or_else(|| ParseError::new(1, 1, "", "missing instructions"))?;
+        let left_right_intructions = instructions
+            .text
+            .char_indices()
+            .map(|(i, c)| match c {
+                'R' => Ok(right),
+                'L' => Ok(left),
+                _ => Err(instructions.error(
+                    &instructions.text[i..i + c.len_utf8()],
+                    "invalid instruction",
+                )),
+            })
+            .collect::<Result<Vec<usize>, ParseError>>()?;
+
+        // Nodes are numbered in the order they are defined, so every target
+        // must be looked up once all of them are known.
+        let mut names = Vec::new();
+        let mut indices: HashMap<String, u32> = HashMap::new();
+        let mut targets_by_node = Vec::new();
+        for line in lines.filter(|line| !line.text.is_empty()) {
+            let (node, targets) = line.split_once("=")?;
+            let (left, right) = targets
+                .trim()
+                .strip_prefix('(')
+                .and_then(|targets| targets.strip_suffix(')'))
+                .and_then(|targets| targets.split_once(','))
+                .ok_or_else(|| line.error(targets.trim(), "expected `(<left>, <right>)`"))?;
+
+            let node = node.trim();
+            let index = *indices.entry(node.to_string()).or_insert_with(|| {
+                names.push(node.to_string());
+                targets_by_node.push(None);
+                names.len() as u32 - 1
+            });
+            targets_by_node[index as usize] = Some((line, left.trim(), right.trim()));
+        }
+
+        let mut links = Vec::with_capacity(names.len());
+        for (line, left, right) in targets_by_node.into_iter().flatten() {
+            let lookup = |target: &str| {
+                indices
+                    .get(target)
+                    .copied()
+                    .ok_or_else(|| line.error(target, "unknown node"))
+            };
+            links.push([lookup(left)?, lookup(right)?]);
+        }
+
+        Ok(Network {
+            left_right_intructions,
+            links,
+            names,
+            indices,
+        })
+    }
+
+    /// Takes `dot=<file>` and `starts=<node>,<node>...` for
+    /// [`Day8::export`].
+    fn parse_with(input: &str, params: &Params) -> Result<Network, Box<dyn Error>> {
+        params.check(&["dot", "starts"])?;
+
+        let network = Self::parse(input)?;
+        starts(&network, params)?;
+        Ok(network)
+    }
+
+    /// Writes the network to the `dot` file as Graphviz DOT, if given, only
+    /// with the nodes reachable from `starts` if those are given too.
+    fn export(network: &Network, params: &Params) -> Result<(), Box<dyn Error>> {
+        if let Some(path) = params.get::<String>("dot")? {
+            fs::write(&path, to_dot(network, &starts(network, params)?))?;
+            info!(path, "wrote the network as DOT");
+        }
+        Ok(())
+    }
+
+    fn part1(network: &Network) -> Meeting {
+        let (Some(first_node), Some(final_node)) = (network.node("AAA"), network.node("ZZZ"))
+        else {
+            let missing = if network.node("AAA").is_none() {
+                "AAA"
+            } else {
+                "ZZZ"
+            };
+            return Meeting::Never(format!("there is no node {missing}"));
+        };
+
+        let instructions = &network.left_right_intructions;
+        let cycle = Cycle::find(
+            (first_node, 0),
+            |&(node, i)| {
+                let next = network.next(node, instructions[i]);
+                (next, (i + 1) % instructions.len())
+            },
+            |&(node, _)| node == final_node,
+        );
+        let Some(steps) = cycle.first_end() else {
+            return Meeting::Never("ZZZ cannot be reached from AAA".to_string());
+        };
+
+        // The cycle search already knows the answer; walking it again is
+        // only worth it when someone watches the steps.
+        if tracing::enabled!(Level::TRACE) || step::active() {
+            let mut node = first_node;
+            for (number_of_steps, &direction) in (1..=steps).zip(instructions.iter().cycle()) {
+                let next = network.next(node, direction);
+                trace!(
+                    node = network.name(node),
+                    left = network.name(network.next(node, 0)),
+                    right = network.name(network.next(node, 1)),
+                    direction = if direction == 0 { "L" } else { "R" },
+                    "step"
+                );
+
+                node = next;
+                step::checkpoint("step", || {
+                    format!("step {number_of_steps}: {}", network.name(node))
+                });
+            }
+        }
+        debug!(steps, "reached the final node");
+
+        Meeting::At(steps)
+    }
+
+    fn part2(network: &Network) -> Meeting {
+        let mut starts: Vec<u32> = network.nodes_ending_with("A").collect();
+        starts.sort_unstable_by_key(|&node| network.name(node));
+        debug!(
+            starts = ?starts.iter().map(|&node| network.name(node)).collect::<Vec<_>>(),
+            "ghosts"
+        );
+        let mut is_end = vec![false; network.len()];
+        for node in network.nodes_ending_with("Z") {
+            is_end[node as usize] = true;
+        }
+
+        let instructions = &network.left_right_intructions;
+        let cycles: Vec<Cycle> = starts
+            .iter()
+            .map(|&start| {
+                let cycle = Cycle::find(
+                    (start, 0),
+                    |&(node, i)| {
+                        let next = network.next(node, instructions[i]);
+                        (next, (i + 1) % instructions.len())
+                    },
+                    |&(node, _)| is_end[node as usize],
+                );
+                let start = network.name(start);
+                debug!(
+                    start,
+                    tail = cycle.tail,
+                    length = cycle.length,
+                    tail_hits = ?cycle.tail_hits,
+                    offsets = ?cycle.offsets,
+                    "ghost cycle"
+                );
+                step::checkpoint("ghosts", || format!("{start}: {cycle:?}"));
+                cycle
+            })
+            .collect();
+
+        match first_common(&cycles) {
+            Ok(steps) => Meeting::At(steps),
+            Err(CycleError::NeverEnds(ghost)) => Meeting::Never(format!(
+                "the ghost starting at {} never reaches a node ending in Z",
+                network.name(starts[ghost])
+            )),
+            Err(err) => Meeting::Never(err.to_string()),
+        }
+    }
+}
+
+/// The nodes named by the `starts` parameter; none if it is not given.
+fn starts(network: &Network, params: &Params) -> Result<Vec<u32>, ParamError> {
+    let Some(names) = params.get::<String>("starts")? else {
+        return Ok(Vec::new());
+    };
+    names
+        .split(',')
+        .map(|name| {
+            network.node(name.trim()).ok_or(ParamError::InvalidValue {
+                key: "starts".to_string(),
+                value: names.clone(),
+            })
+        })
+        .collect()
+}
+
+/// When the walk first reaches its end, every ghost at once for part 2, or
+/// why it never does.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Meeting {
+    At(u64),
+    Never(String),
+}
+
+impl fmt::Display for Meeting {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Meeting::At(steps) => write!(f, "{steps}"),
+            Meeting::Never(reason) => write!(f, "never: {reason}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part1_example() {
+        let network = Day8::parse(EXAMPLE_1).unwrap();
+        assert_eq!(Day8::part1(&network), Meeting::At(2));
+    }
+
+    #[test]
+    fn part1_repeats_instructions() {
+        let network =
+            Day8::parse("LLR\n\nAAA = (BBB, BBB)\nBBB = (AAA, ZZZ)\nZZZ = (ZZZ, ZZZ)").unwrap();
+        assert_eq!(Day8::part1(&network), Meeting::At(6));
+    }
+
+    #[test]
+    fn nodes_are_interned_in_definition_order() {
+        let network = Day8::parse(EXAMPLE_1).unwrap();
+        assert_eq!(network.len(), 7);
+        assert_eq!(network.node("AAA"), Some(0));
+        assert_eq!(network.node("ZZZ"), Some(6));
+        assert_eq!(network.node("XXX"), None);
+        assert_eq!(network.links[0], [1, 2]);
+        assert_eq!(network.name(network.next(2, 0)), "ZZZ");
+        assert_eq!(network.nodes_ending_with("Z").collect::<Vec<_>>(), [6]);
+    }
+
+    #[test]
+    fn dot_export() {
+        let network = Day8::parse(EXAMPLE_2).unwrap();
+        let dot = to_dot(&network, &[]);
+        assert!(dot.starts_with("digraph network {\n"));
+        assert!(dot.contains("    \"11A\" [style=filled, fillcolor=palegreen];\n"));
+        assert!(dot.contains("    \"22Z\" [style=filled, fillcolor=salmon];\n"));
+        assert!(dot.contains("    \"11B\" -> \"XXX\" [label=\"L\"];\n"));
+        assert!(dot.contains("    \"11B\" -> \"11Z\" [label=\"R\"];\n"));
+        assert!(dot.contains("    \"22B\" -> \"22C\" [label=\"LR\"];\n"));
+
+        let reachable = to_dot(&network, &[network.node("22B").unwrap()]);
+        assert_eq!(
+            reachable,
+            "digraph network {
+    \"22B\" [style=filled, fillcolor=palegreen];
+    \"22C\";
+    \"22Z\" [style=filled, fillcolor=salmon];
+    \"22B\" -> \"22C\" [label=\"LR\"];
+    \"22C\" -> \"22Z\" [label=\"LR\"];
+    \"22Z\" -> \"22B\" [label=\"LR\"];
+}
+"
+        );
+    }
+
+    #[test]
+    fn dot_escapes_names() {
+        let network = Day8::parse("L\n\na\"b = (c\\d, c\\d)\nc\\d = (c\\d, c\\d)").unwrap();
+        assert!(to_dot(&network, &[]).contains("    \"a\\\"b\" -> \"c\\\\d\" [label=\"LR\"];\n"));
+    }
+
+    #[test]
+    fn dot_is_written_by_export_not_parse() {
+        let path = std::env::temp_dir().join(format!("day_8_{}.dot", std::process::id()));
+        let params: Params = format!("dot={}", path.display()).parse().unwrap();
+
+        let network = Day8::parse_with(EXAMPLE_2, &params).unwrap();
+        assert!(!path.exists());
+        Day8::export(&network, &params).unwrap();
+        let dot = fs::read_to_string(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+        assert_eq!(dot, to_dot(&network, &[]));
+    }
+
+    #[test]
+    fn unknown_start_is_an_error() {
+        let params: Params = "starts=11A,33A".parse().unwrap();
+        let err = Day8::parse_with(EXAMPLE_2, &params).err().unwrap();
+        assert_eq!(
+            err.to_string(),
+            "invalid value `11A,33A` for parameter `starts`"
+        );
+    }
+
+    #[test]
+    fn part1_explains_a_missing_or_unreachable_end() {
+        let network = Day8::parse(EXAMPLE_2).unwrap();
+        assert_eq!(
+            Day8::part1(&network).to_string(),
+            "never: there is no node AAA"
+        );
+
+        let network = Day8::parse("L\n\nAAA = (AAA, AAA)\nZZZ = (ZZZ, ZZZ)").unwrap();
+        assert_eq!(
+            Day8::part1(&network).to_string(),
+            "never: ZZZ cannot be reached from AAA"
+        );
+    }
+
+    #[test]
+    fn part2_example() {
+        let network = Day8::parse(EXAMPLE_2).unwrap();
+        assert_eq!(Day8::part2(&network), Meeting::At(6));
+    }
+
+    #[test]
+    fn part2_without_the_lcm_shortcut() {
+        // 11A ends at steps 1, 4, 7, ... and 22A at 2, 4, 6, ..., so taking
+        // the lcm of the first hits would give 2.
+        let network = Day8::parse(
+            "LR\n\n11A = (11Z, 11Z)\n11Z = (11B, 11B)\n11B = (11A, 11A)\n\
+             22A = (22B, 22B)\n22B = (22Z, 22Z)\n22Z = (22B, 22B)",
+        )
+        .unwrap();
+        assert_eq!(Day8::part2(&network), Meeting::At(4));
+    }
+
+    #[test]
+    fn part2_explains_a_ghost_that_never_ends() {
+        let network =
+            Day8::parse("L\n\n11A = (11Z, 11Z)\n11Z = (11Z, 11Z)\n22A = (22A, 22A)").unwrap();
+        assert_eq!(
+            Day8::part2(&network).to_string(),
+            "never: the ghost starting at 22A never reaches a node ending in Z"
+        );
+    }
+
+    #[test]
+    fn invalid_instruction_is_an_error() {
+        let err = Day8::parse("RLX\n\nAAA = (AAA, AAA)").err().unwrap();
+        assert_eq!(
+            err.to_string(),
+            "line 1, column 3: invalid instruction: `X`"
+        );
+    }
+
+    #[test]
+    fn missing_instructions_is_an_error() {
+        for input in ["", "\n\nAAA = (AAA, AAA)"] {
+            let err = Day8::parse(input).err().unwrap();
+            assert_eq!(err.to_string(), "line 1, column 1: missing instructions");
+        }
+    }
+
+    #[test]
+    fn unknown_node_is_an_error() {
+        let err = Day8::parse("RL\n\nAAA = (BBB, AAA)").err().unwrap();
+        assert_eq!(err.to_string(), "line 3, column 8: unknown node: `BBB`");
+    }
+}